@@ -1,4 +1,30 @@
 resource_RNA_name = RNA
+resource_DNA_name = DNA
+resource_Knowledge_name = Knowledge
+resource_Food_name = Food
+resource_Lumber_name = Lumber
+resource_Stone_name = Stone
+resource_Money_name = Money
+resource_Iron_name = Iron
+resource_Coal_name = Coal
+resource_Oil_name = Oil
+resource_Uranium_name = Uranium
+resource_Mana_name = Mana
+resource_Plywood_name = Plywood
+resource_Brick_name = Brick
+resource_WroughtIron_name = Wrought Iron
+resource_SheetMetal_name = Sheet Metal
+resource_Crates_name = Crates
+resource_Containers_name = Containers
+
+# species stuff
+species_protoplasm_title = Protoplasm
+species_sporgar_title = Sporgar
+species_shroomi_title = Shroomi
+species_molding_title = Molding
+species_entish_title = Entish
+species_cacti_title = Cacti
+species_pinguicula_title = Pinguicula
 
 # evolution stuff
 evo_rna = Creates 1 RNA
@@ -47,4 +73,397 @@ evo_multicellular_title = Multicellular
 evo_multicellular_desc = Evolve Multicellular
 evo_multicellular_effect = Decreases cost of producing new nucleus.
 
-evo_nucleus_boost = Increases DNA generation from nucleus
\ No newline at end of file
+evo_nucleus_boost = Increases DNA generation from nucleus
+
+evo_bilateral_symmetry_title = Bilateral Symmetry
+evo_bilateral_symmetry_desc = Evolve Bilateral Symmetry
+
+evo_poikilohydric_title = Poikilohydric
+evo_poikilohydric_desc = Evolve Poikilohydric
+
+evo_spores_title = Spores
+evo_spores_desc = Evolve Spores
+
+evo_bryophyte_title = Bryophyte
+evo_bryophyte_desc = Evolve Bryophyte
+evo_bryophyte_effect = Evolve into a bryophyte, the last step before sentience
+
+evo_sentience_title = Sentience
+evo_sentience_desc = Evolve Sentience
+evo_sentience_effect = Become a sentient species and found your first city
+
+# city stuff
+city_food_title = Gather Food
+city_food_desc = Gathers 1 Food
+
+city_lumber_title = Gather Lumber
+city_lumber_desc = Gathers 1 Lumber
+
+city_stone_title = Gather Stone
+city_stone_desc = Gathers 1 Stone
+
+city_knowledge_title = Think
+city_knowledge_desc = Creates 1 Knowledge
+
+city_basic_housing_title = Cabin
+city_basic_housing_desc = A simple cabin
+city_basic_housing_effect = +1 max citizen
+
+city_farm_title = Farm
+city_farm_desc = Fields of crops to feed your citizens
+city_farm_effect = +1 farmer job
+
+city_university_title = University
+city_university_desc = A place of higher learning
+city_university_effect = +500 max Knowledge and +1 professor job
+
+job_farmer_title = Farmer
+job_lumberjack_title = Lumberjack
+job_quarry_worker_title = Quarry Worker
+job_professor_title = Professor
+
+# research stuff
+tech_housing_title = Housing
+tech_housing_desc = Learn to build cabins
+tech_housing_effect = Unlocks the Cabin
+
+tech_agriculture_title = Agriculture
+tech_agriculture_desc = Learn to grow crops
+tech_agriculture_effect = Unlocks the Farm and Farmers
+
+tech_stone_axe_title = Stone Axe
+tech_stone_axe_desc = Learn to fell trees with stone tools
+tech_stone_axe_effect = Unlocks Lumberjacks
+
+tech_mining_title = Mining
+tech_mining_desc = Learn to quarry stone
+tech_mining_effect = Unlocks Quarry Workers
+
+tech_irrigation_title = Irrigation
+tech_irrigation_desc = Bring water to the fields
+tech_irrigation_effect = Farmers produce 40% more Food
+
+tech_science_title = Scientific Method
+tech_science_desc = Organize the pursuit of knowledge
+tech_science_effect = Unlocks the University and Professors
+
+tech_currency_title = Currency
+tech_currency_desc = Invent money as a medium of exchange
+tech_currency_effect = Unlocks Money and taxes
+
+tech_government_title = Government
+tech_government_desc = Formalize how your society is ruled
+tech_government_effect = Allows changing the government from the Civics tab
+
+# civic stuff
+govern_anarchy_title = Anarchy
+govern_anarchy_desc = No one is in charge. Taxes can't be collected and morale suffers.
+govern_autocracy_title = Autocracy
+govern_autocracy_desc = A single ruler. +10% production, -5% morale.
+govern_democracy_title = Democracy
+govern_democracy_desc = Rule by the people. +5% morale, -5% production.
+govern_oligarchy_title = Oligarchy
+govern_oligarchy_desc = Rule by the wealthy few. +25% tax income, -2% morale.
+govern_theocracy_title = Theocracy
+govern_theocracy_desc = Rule by the clergy. +10% morale, -25% Knowledge from professors.
+govern_technocracy_title = Technocracy
+govern_technocracy_desc = Rule by experts. +10% Knowledge from professors, -10% tax income.
+
+city_mine_title = Mine
+city_mine_desc = Tunnels dug deep for ore
+city_mine_effect = +1 miner job
+
+city_foundry_title = Foundry
+city_foundry_desc = A workshop for refining raw materials
+city_foundry_effect = +1 craftsman
+
+job_miner_title = Miner
+
+tech_foundry_title = Foundry
+tech_foundry_desc = Learn to refine raw materials
+tech_foundry_effect = Unlocks the Foundry and crafting of Plywood and Brick
+
+tech_metal_working_title = Metal Working
+tech_metal_working_desc = Learn to mine and work iron
+tech_metal_working_effect = Unlocks the Mine, Miners and crafting of Wrought Iron and Sheet Metal
+
+city_shed_title = Shed
+city_shed_desc = A place to keep building materials
+city_shed_effect = +300 max Lumber and Stone, +100 max Iron and room for 10 crates
+
+city_silo_title = Silo
+city_silo_desc = Keeps grain dry and safe
+city_silo_effect = +500 max Food
+
+city_warehouse_title = Warehouse
+city_warehouse_desc = A large building for bulk storage
+city_warehouse_effect = +500 max Lumber and Stone, +250 max Iron and room for 10 containers
+
+tech_storage_title = Storage
+tech_storage_desc = Learn to store goods in bulk
+tech_storage_effect = Unlocks the Shed, the Silo and crafting of Crates
+
+tech_warehouse_title = Warehouse
+tech_warehouse_desc = Learn to build large storage buildings
+tech_warehouse_effect = Unlocks the Warehouse and crafting of Containers
+
+city_trade_post_title = Trade Post
+city_trade_post_desc = A meeting place for foreign merchants
+city_trade_post_effect = +1 trade route
+
+tech_trade_title = Trade
+tech_trade_desc = Open your city to foreign merchants
+tech_trade_effect = Unlocks the Market and the Trade Post
+
+city_coal_mine_title = Coal Mine
+city_coal_mine_desc = A deep shaft for digging up coal
+city_coal_mine_effect = +1 coal miner job
+
+city_oil_well_title = Oil Well
+city_oil_well_desc = Pumps crude oil out of the ground
+city_oil_well_effect = Produces 0.4 Oil per second and +500 max Oil
+
+city_coal_power_title = Coal Power Plant
+city_coal_power_desc = Burns coal to generate electricity
+city_coal_power_effect = Generates 5 MW of power
+
+city_oil_power_title = Oil Power Plant
+city_oil_power_desc = Burns oil to generate electricity
+city_oil_power_effect = Generates 6 MW of power
+
+city_fission_power_title = Fission Reactor
+city_fission_power_desc = Splits uranium atoms to generate electricity
+city_fission_power_effect = Generates 14 MW of power
+
+job_coal_miner_title = Coal Miner
+
+tech_coal_mining_title = Coal Mining
+tech_coal_mining_desc = Learn to mine coal
+tech_coal_mining_effect = Unlocks the Coal Mine and Coal Miners
+
+tech_electricity_title = Electricity
+tech_electricity_desc = Harness the power of electricity
+tech_electricity_effect = Unlocks the Coal Power Plant. Workers in powered mines and foundries work 50% faster.
+
+tech_oil_title = Oil Drilling
+tech_oil_desc = Learn to drill for oil
+tech_oil_effect = Unlocks the Oil Well and the Oil Power Plant
+
+tech_fission_title = Nuclear Fission
+tech_fission_desc = Split the atom
+tech_fission_effect = Unlocks the Fission Reactor. Coal miners also dig up Uranium.
+
+season_spring = Spring
+season_summer = Summer
+season_autumn = Autumn
+season_winter = Winter
+
+weather_sunny = Sunny
+weather_rain = Rain
+weather_snow = Snow
+weather_wind = Wind
+
+event_meteor = A meteor crashed nearby, leaving behind { $amount } { $resource }
+event_fire = A fire broke out and destroyed { $amount } Lumber
+event_trade_boom = Merchants are flocking in, market prices soared
+event_festival = The citizens are holding a festival, production is up 15% for a minute
+
+message_info = Info
+message_warning = Warnings
+message_achievement = Achievements
+message_event = Events
+
+log_evolution_unlocked = New evolution available: { $title }
+log_research_unlocked = New research available: { $title }
+log_purchased = Bought { $title }
+log_sentience = Your species has become sentient as the { $species }
+log_brownout = Brownout! The power plants can't keep up with demand
+log_starved = The food ran out, citizens are starving to death
+log_reloaded_lang = Reloaded the translations
+log_reloaded_themes = Reloaded the themes
+
+tech_mad_title = Mutual Destruction
+tech_mad_desc = Arm enough warheads to end civilization as we know it
+tech_mad_effect = Unlocks the ability to reset the run for Plasmids
+
+prestige_mad_title = Launch the Nukes
+prestige_mad_desc = Wipe out civilization and let life start over from the primordial soup
+prestige_mad_effect = Resets the run, awarding { $plasmids } Plasmids. Every Plasmid permanently adds 1% production.
+
+log_prestige = Civilization ended in nuclear fire. { $plasmids } Plasmids were gained from its ashes.
+
+achieve_fungi_title = Mycologist
+achieve_fungi_desc = Evolve every fungi species. +2% production.
+achieve_plants_title = Botanist
+achieve_plants_desc = Evolve every plant species. +2% production.
+achieve_speedrun_title = Fast Learner
+achieve_speedrun_desc = Reach sentience in under 10 minutes. +1% production.
+achieve_no_membrane_title = Thin Skinned
+achieve_no_membrane_desc = Reach sentience without ever buying a membrane. +1% production.
+achieve_metropolis_title = Metropolis
+achieve_metropolis_desc = Have 50 citizens at once. +1% production.
+achieve_prestige_title = Mutually Assured
+achieve_prestige_desc = Reset a run for Plasmids
+
+log_achievement = Achievement unlocked: { $title }
+
+biome_grassland_title = Grassland
+biome_grassland_desc = Fertile plains. Farmers produce 20% more.
+biome_oceanic_title = Oceanic
+biome_oceanic_desc = A world of islands. Farmers produce 10% more, quarry workers 10% less. Cacti can't evolve here.
+biome_desert_title = Desert
+biome_desert_desc = Dry and barren. Farmers produce 25% less, quarry workers 20% more. Molding and Entish can't evolve here.
+biome_volcanic_title = Volcanic
+biome_volcanic_desc = Rich in minerals. Farmers produce 10% less, miners and coal miners 20% more. Shroomi and Pinguicula can't evolve here.
+biome_tundra_title = Tundra
+biome_tundra_desc = Frozen wastes. Farmers produce 25% less, miners 10% more. Sporgar and Cacti can't evolve here.
+biome_forest_title = Forest
+biome_forest_desc = Covered in trees. Lumberjacks produce 20% more. Cacti can't evolve here.
+
+universe_standard_title = Standard
+universe_standard_desc = The universe as we know it. No special rules.
+universe_heavy_title = Heavy Gravity
+universe_heavy_desc = Everything weighs more. Costs are 25% higher and production is 10% lower, but resets award 25% more Plasmids.
+universe_antimatter_title = Antimatter
+universe_antimatter_desc = Matter is reversed. Production is 10% higher, but resets award half the Plasmids.
+universe_evil_title = Evil
+universe_evil_desc = A cruel place. Production is 10% lower, but resets award 20% more Plasmids.
+universe_micro_title = Micro
+universe_micro_desc = Everything is tiny. Costs are 25% lower, but resets award half the Plasmids.
+universe_magic_title = Magic
+universe_magic_desc = Magic is real. Mana slowly accumulates and boosts production by up to 10% when full.
+
+challenge_no_crafting_title = No Crafting
+challenge_no_crafting_desc = Refined resources can't be crafted
+challenge_no_trade_title = No Trade
+challenge_no_trade_desc = The market and trade routes are closed
+challenge_weak_mastery_title = Weak Mastery
+challenge_weak_mastery_desc = Achievement bonuses are halved
+challenge_joyless_title = Joyless
+challenge_joyless_desc = Morale can't rise above 100%
+challenge_decay_title = Decay
+challenge_decay_desc = Stockpiled raw resources slowly rot away
+
+achieve_challenger_title = Challenger
+achieve_challenger_desc = Reset a run played under any challenge. +1% production.
+achieve_masochist_title = Masochist
+achieve_masochist_desc = Reset a run under every challenge. +3% production.
+
+notation_standard = Standard
+notation_short = Short
+notation_scientific = Scientific
+notation_engineering = Engineering
+
+theme_dark = Dark
+theme_light = Light
+theme_classic = Classic
+theme_high_contrast = High contrast
+
+glyphs_latin = Latin
+glyphs_cyrillic = Cyrillic
+glyphs_japanese = Japanese
+glyphs_korean = Korean
+glyphs_chinese_common = Chinese (common)
+glyphs_chinese_full = Chinese (full)
+glyphs_thai = Thai
+glyphs_vietnamese = Vietnamese
+
+# interface stuff
+panel_resources = Resources
+panel_main = Main
+panel_stats = Stats
+panel_messages = Messages
+
+tab_evolve = Evolve
+tab_city = City
+tab_research = Research
+tab_civics = Civics
+tab_storage = Storage
+tab_market = Market
+tab_crafting = Crafting
+tab_stats = Stats
+tab_achievements = Achievements
+tab_settings = Settings
+
+ui_prehistoric = Prehistoric
+ui_date = Year { $year }, Day { $day }
+ui_plasmids = Plasmids: { $plasmids }
+ui_production_bonus = +{ $bonus }% production
+ui_credits = Evolve by John
+ui_evolving = Evolving
+ui_completed = Completed
+ui_cheats = Cheats
+ui_cheat_fill = Fill resources
+ui_cheat_reset = Reset Save
+cheat_reset_desc = Throws the current run away and starts over from scratch
+ui_confirm = Are you sure?
+ui_yes = Yes
+ui_no = No
+ui_locked = Locked
+ui_history = History
+
+ui_power = Power: { $supply } / { $demand } MW
+ui_power_brownout = Power: { $supply } / { $demand } MW (brownout)
+ui_power_plant = +{ $power } MW, burns { $amount } { $resource } /s
+ui_power_consumer = { $power } MW
+ui_power_running = Running: { $running }/{ $enabled }, switched off: { $off }
+ui_grid_running = { $title }: { $running }/{ $built } running
+ui_switch_off = Off
+ui_switch_on = On
+
+ui_citizens = Citizens: { $citizens }/{ $max }
+ui_unemployed = Unemployed
+ui_government = Government: { $government }
+ui_revolution = Revolution possible in { $cooldown }s
+ui_tax_rate = Tax rate
+ui_morale = Morale: { $morale }%
+ui_buy = Buy { $amount }
+ui_sell = Sell { $amount }
+ui_trade_routes = Trade routes: { $active }/{ $slots }
+ui_route_buy = Buy { $amount } { $resource } /s
+ui_route_sell = Sell { $amount } { $resource } /s
+ui_craftsmen = Craftsmen: { $craftsmen }/{ $foundries }
+ui_craft_all = All
+
+ui_choose_universe = Choose the universe to evolve in
+ui_choose_challenges = Pick any challenges for this run
+ui_choose_planet = Choose the planet life will evolve on
+ui_planet = { $biome }, { $orbit } day orbit
+
+ui_achievements_unlocked = Unlocked: { $unlocked }/{ $total }
+ui_achievements_bonus = Bonus: +{ $bonus }% production
+
+stats_run = This run
+stats_lifetime = Lifetime
+stats_time = Time played
+stats_evolution_time = Evolution time
+stats_resets = Resets
+stats_species = Species reached
+stats_active = Active
+stats_completed = { $completed } completed
+stats_gained = Gained
+stats_spent = Spent
+stats_lifetime_gained = Lifetime gained
+stats_lifetime_spent = Lifetime spent
+stats_action = Action
+stats_bought = Bought
+stats_lifetime_bought = Lifetime bought
+
+settings_notation = Number notation
+settings_notation_example = Example: { $example }
+settings_autosave = Autosave interval (s)
+settings_ui_scale = UI scale
+settings_theme = Theme
+settings_themes_dir = Custom themes are read from the { $dir } folder
+settings_language = Language
+settings_confirm = Confirm destructive actions
+settings_free_layout = Free layout
+settings_free_layout_tooltip = Lets panels be moved, resized and docked into each other
+settings_reset_layout = Reset layout
+settings_font_size = Font size
+settings_font = Font
+settings_fallback_font = Fallback font
+settings_fallback_font_tooltip = Fills in the glyphs the main font doesn't have
+settings_builtin_font = Built-in
+settings_glyphs = Glyphs
+settings_fonts_dir = Fonts are read from { $dir }
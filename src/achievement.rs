@@ -0,0 +1,120 @@
+use crate::challenge::Challenge;
+use crate::message::{Arg, MessageKind};
+use crate::race::Species;
+use crate::{loc, msg, util, Game};
+use std::borrow::Cow;
+
+/// Sentience has to be reached this many seconds into a run for the speedrun achievement
+const SPEEDRUN: u64 = 600;
+
+pub struct Achievement {
+    pub id: &'static str,
+    pub(crate) title: &'static str,
+    pub(crate) desc: &'static str,
+    /// Checked every second until it holds once, the achievement stays unlocked from then on
+    condition: fn(&Game) -> bool,
+    /// Production bonus granted once unlocked
+    pub bonus: f32,
+}
+
+impl Achievement {
+    pub fn title(&self) -> Cow<'static, str> {
+        loc!(self.title)
+    }
+
+    pub fn description(&self) -> Cow<'static, str> {
+        loc!(self.desc)
+    }
+}
+
+pub const ACHIEVEMENTS: &[Achievement] = &[
+    Achievement {
+        id: "fungi",
+        title: "achieve_fungi_title",
+        desc: "achieve_fungi_desc",
+        condition: |game| {
+            [Species::Sporgar, Species::Shroomi, Species::Molding]
+                .iter()
+                .all(|species| game.profile.stats.species.contains(species))
+        },
+        bonus: 0.02,
+    },
+    Achievement {
+        id: "plants",
+        title: "achieve_plants_title",
+        desc: "achieve_plants_desc",
+        condition: |game| {
+            [Species::Entish, Species::Cacti, Species::Pinguicula]
+                .iter()
+                .all(|species| game.profile.stats.species.contains(species))
+        },
+        bonus: 0.02,
+    },
+    Achievement {
+        id: "speedrun",
+        title: "achieve_speedrun_title",
+        desc: "achieve_speedrun_desc",
+        condition: |game| game.stats.evolution_time.map_or(false, |time| time <= SPEEDRUN),
+        bonus: 0.01,
+    },
+    Achievement {
+        id: "no_membrane",
+        title: "achieve_no_membrane_title",
+        desc: "achieve_no_membrane_desc",
+        condition: |game| game.evolution.sentience == Some(true) && game.evolution.membrane.unwrap_or(0) == 0,
+        bonus: 0.01,
+    },
+    Achievement {
+        id: "metropolis",
+        title: "achieve_metropolis_title",
+        desc: "achieve_metropolis_desc",
+        condition: |game| game.civic.citizens >= 50,
+        bonus: 0.01,
+    },
+    Achievement {
+        id: "challenger",
+        title: "achieve_challenger_title",
+        desc: "achieve_challenger_desc",
+        condition: |game| !game.profile.stats.challenges.is_empty(),
+        bonus: 0.01,
+    },
+    Achievement {
+        id: "masochist",
+        title: "achieve_masochist_title",
+        desc: "achieve_masochist_desc",
+        condition: |game| Challenge::iter().all(|challenge| game.profile.stats.challenges.contains_key(&challenge)),
+        bonus: 0.03,
+    },
+    Achievement {
+        id: "prestige",
+        title: "achieve_prestige_title",
+        desc: "achieve_prestige_desc",
+        condition: |game| game.profile.resets > 0,
+        bonus: 0.0,
+    },
+];
+
+/// Unlocks every achievement whose condition holds, stamping it with the current time
+pub fn check(game: &mut Game) {
+    for achievement in ACHIEVEMENTS {
+        if game.profile.achievements.contains_key(achievement.id) || !(achievement.condition)(game) {
+            continue;
+        }
+
+        game.profile
+            .achievements
+            .insert(achievement.id.to_string(), util::now());
+        let title = Arg::Key(achievement.title.to_string());
+        game.log.push(MessageKind::Achievement, msg!("log_achievement", title));
+    }
+}
+
+/// Production multiplier from the bonuses of every unlocked achievement, weakened by the weak mastery challenge
+pub fn mult(game: &Game) -> f32 {
+    let bonus: f32 = ACHIEVEMENTS
+        .iter()
+        .filter(|achievement| game.profile.achievements.contains_key(achievement.id))
+        .map(|achievement| achievement.bonus)
+        .sum();
+    1.0 + bonus * game.rules.mastery()
+}
@@ -1,6 +1,9 @@
+use crate::message::{Arg, MessageKind};
 use crate::race::Species;
 use crate::resource::ResourceType::*;
-use crate::{loc, ACTIONS};
+use crate::stats;
+use crate::tech::TECHS;
+use crate::{loc, msg, prestige, ACTIONS};
 use crate::{resource::Cost, Game};
 use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
@@ -8,20 +11,22 @@ use std::ops::Index;
 
 type Hook<T = ()> = fn(&Game) -> T;
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Category {
     Evolution,
+    City,
+    Research,
 }
 
 #[derive(Clone)]
 pub struct Action {
     pub id: &'static str,
-    title: &'static str,
-    desc: &'static str,
-    effect: Option<Hook<Cow<'static, str>>>,
-    cost: Option<Hook<Vec<Cost>>>,
-    action: fn(&Self, &mut Game),
-    count: Option<Hook<Option<u32>>>,
+    pub(crate) title: &'static str,
+    pub(crate) desc: &'static str,
+    pub(crate) effect: Option<Hook<Cow<'static, str>>>,
+    pub(crate) cost: Option<Hook<Vec<Cost>>>,
+    pub(crate) action: fn(&Self, &mut Game),
+    pub(crate) count: Option<Hook<Option<u32>>>,
 }
 
 impl std::fmt::Debug for Action {
@@ -43,8 +48,14 @@ impl Action {
         self.effect.map(|hook| hook(game))
     }
 
+    /// The cost of the action, scaled by the universe
     pub fn cost(&self, game: &Game) -> Vec<Cost> {
-        self.cost.map(|hook| hook(game)).unwrap_or_default()
+        let mult = game.universe().costs();
+        let mut costs = self.cost.map(|hook| hook(game)).unwrap_or_default();
+        for cost in costs.iter_mut() {
+            cost.amount = (cost.amount * mult).round();
+        }
+        costs
     }
 
     pub fn execute(&self, game: &mut Game) {
@@ -55,14 +66,20 @@ impl Action {
         self.count.map(|hook| hook(game)).flatten()
     }
 
-    fn pay(&self, game: &mut Game) -> bool {
+    pub(crate) fn pay(&self, game: &mut Game) -> bool {
         let costs = self.cost(game);
         if game.check_costs(&costs) {
+            if !costs.is_empty() {
+                let title = Arg::Key(self.title.to_string());
+                game.log.push(MessageKind::Info, msg!("log_purchased", title));
+            }
+
             for cost in costs {
                 let Cost { resource, amount } = cost;
                 game.resources[resource].amount -= amount;
-                // TODO: update stats
+                stats::spend(game, resource, amount);
             }
+            stats::buy(game, self.id);
 
             return true;
         }
@@ -112,10 +129,12 @@ impl Index<Category> for ActionHolder {
     type Output = Vec<Action>;
 
     fn index(&self, index: Category) -> &Self::Output {
-        &self.inner[&index]
+        static EMPTY: Vec<Action> = Vec::new();
+        self.inner.get(&index).unwrap_or(&EMPTY)
     }
 }
 
+#[macro_export]
 macro_rules! cost {
     ( $game:expr, $($resource:ident => $cell:tt, $base:expr, $mult:expr),* ) => {
         vec![$(
@@ -525,10 +544,394 @@ pub const ACTION_SENTIENCE: Action = Action {
                 unreachable!()
             }
 
+            if let Some(planet) = &game.planet {
+                races.retain(|&species| planet.biome.allows(species));
+            }
+
             game.race.species = races[game.rng.usize(0..races.len())];
 
-            game.become_sentient()
+            game.become_sentient(&mut holder)
+        }
+    },
+    count: None,
+};
+
+/////////////////////////////////////////////////////////////
+
+/// Scales a base cost by `mult` for every copy of a structure that has already been built
+fn scale(base: f32, mult: f32, count: Option<u32>) -> f32 {
+    (base * mult.powi(count.unwrap_or(0) as i32)).round()
+}
+
+pub const ACTION_FOOD: Action = Action {
+    id: "city-food",
+    title: "city_food_title",
+    desc: "city_food_desc",
+    effect: None,
+    cost: None,
+    action: |_, game| {
+        game.mod_res(Food, 1.0, true, false);
+    },
+    count: None,
+};
+
+pub const ACTION_LUMBER: Action = Action {
+    id: "city-lumber",
+    title: "city_lumber_title",
+    desc: "city_lumber_desc",
+    effect: None,
+    cost: None,
+    action: |_, game| {
+        game.mod_res(Lumber, 1.0, true, false);
+    },
+    count: None,
+};
+
+pub const ACTION_STONE: Action = Action {
+    id: "city-stone",
+    title: "city_stone_title",
+    desc: "city_stone_desc",
+    effect: None,
+    cost: None,
+    action: |_, game| {
+        game.mod_res(Stone, 1.0, true, false);
+    },
+    count: None,
+};
+
+pub const ACTION_KNOWLEDGE: Action = Action {
+    id: "city-knowledge",
+    title: "city_knowledge_title",
+    desc: "city_knowledge_desc",
+    effect: None,
+    cost: None,
+    action: |_, game| {
+        game.mod_res(Knowledge, 1.0, true, false);
+    },
+    count: None,
+};
+
+pub const ACTION_BASIC_HOUSING: Action = Action {
+    id: "city-basic_housing",
+    title: "city_basic_housing_title",
+    desc: "city_basic_housing_desc",
+    effect: Some(|_| loc!("city_basic_housing_effect")),
+    cost: Some(|game| cost!(Lumber => scale(10.0, 1.25, game.city.basic_housing))),
+    action: |s, game| {
+        if s.pay(game) {
+            inc!(game.city.basic_housing);
+        }
+    },
+    count: Some(|game| game.city.basic_housing),
+};
+
+pub const ACTION_FARM: Action = Action {
+    id: "city-farm",
+    title: "city_farm_title",
+    desc: "city_farm_desc",
+    effect: Some(|_| loc!("city_farm_effect")),
+    cost: Some(|game| {
+        cost! {
+            Lumber => scale(20.0, 1.3, game.city.farm),
+            Stone => scale(10.0, 1.3, game.city.farm)
+        }
+    }),
+    action: |s, game| {
+        if s.pay(game) {
+            inc!(game.city.farm);
+        }
+    },
+    count: Some(|game| game.city.farm),
+};
+
+pub const ACTION_UNIVERSITY: Action = Action {
+    id: "city-university",
+    title: "city_university_title",
+    desc: "city_university_desc",
+    effect: Some(|_| loc!("city_university_effect")),
+    cost: Some(|game| {
+        cost! {
+            Lumber => scale(100.0, 1.5, game.city.university),
+            Stone => scale(75.0, 1.5, game.city.university)
+        }
+    }),
+    action: |s, game| {
+        if s.pay(game) {
+            inc!(game.city.university);
+        }
+    },
+    count: Some(|game| game.city.university),
+};
+
+pub const ACTION_MINE: Action = Action {
+    id: "city-mine",
+    title: "city_mine_title",
+    desc: "city_mine_desc",
+    effect: Some(|_| loc!("city_mine_effect")),
+    cost: Some(|game| {
+        cost! {
+            Lumber => scale(60.0, 1.3, game.city.mine),
+            Stone => scale(40.0, 1.3, game.city.mine)
+        }
+    }),
+    action: |s, game| {
+        if s.pay(game) {
+            inc!(game.city.mine);
+        }
+    },
+    count: Some(|game| game.city.mine),
+};
+
+pub const ACTION_FOUNDRY: Action = Action {
+    id: "city-foundry",
+    title: "city_foundry_title",
+    desc: "city_foundry_desc",
+    effect: Some(|_| loc!("city_foundry_effect")),
+    cost: Some(|game| {
+        cost! {
+            Lumber => scale(50.0, 1.36, game.city.foundry),
+            Stone => scale(60.0, 1.36, game.city.foundry)
+        }
+    }),
+    action: |s, game| {
+        if s.pay(game) {
+            inc!(game.city.foundry);
+        }
+    },
+    count: Some(|game| game.city.foundry),
+};
+
+pub const ACTION_SHED: Action = Action {
+    id: "city-shed",
+    title: "city_shed_title",
+    desc: "city_shed_desc",
+    effect: Some(|_| loc!("city_shed_effect")),
+    cost: Some(|game| {
+        cost! {
+            Lumber => scale(30.0, 1.22, game.city.shed),
+            Stone => scale(25.0, 1.22, game.city.shed)
+        }
+    }),
+    action: |s, game| {
+        if s.pay(game) {
+            inc!(game.city.shed);
+        }
+    },
+    count: Some(|game| game.city.shed),
+};
+
+pub const ACTION_SILO: Action = Action {
+    id: "city-silo",
+    title: "city_silo_title",
+    desc: "city_silo_desc",
+    effect: Some(|_| loc!("city_silo_effect")),
+    cost: Some(|game| {
+        cost! {
+            Lumber => scale(40.0, 1.25, game.city.silo),
+            Stone => scale(30.0, 1.25, game.city.silo)
+        }
+    }),
+    action: |s, game| {
+        if s.pay(game) {
+            inc!(game.city.silo);
+        }
+    },
+    count: Some(|game| game.city.silo),
+};
+
+pub const ACTION_WAREHOUSE: Action = Action {
+    id: "city-warehouse",
+    title: "city_warehouse_title",
+    desc: "city_warehouse_desc",
+    effect: Some(|_| loc!("city_warehouse_effect")),
+    cost: Some(|game| {
+        cost! {
+            Plywood => scale(15.0, 1.26, game.city.warehouse),
+            Brick => scale(20.0, 1.26, game.city.warehouse)
+        }
+    }),
+    action: |s, game| {
+        if s.pay(game) {
+            inc!(game.city.warehouse);
+        }
+    },
+    count: Some(|game| game.city.warehouse),
+};
+
+pub const ACTION_TRADE_POST: Action = Action {
+    id: "city-trade_post",
+    title: "city_trade_post_title",
+    desc: "city_trade_post_desc",
+    effect: Some(|_| loc!("city_trade_post_effect")),
+    cost: Some(|game| {
+        cost! {
+            Lumber => scale(60.0, 1.36, game.city.trade_post),
+            Stone => scale(40.0, 1.36, game.city.trade_post)
+        }
+    }),
+    action: |s, game| {
+        if s.pay(game) {
+            inc!(game.city.trade_post);
         }
     },
+    count: Some(|game| game.city.trade_post),
+};
+
+pub const ACTION_COAL_MINE: Action = Action {
+    id: "city-coal_mine",
+    title: "city_coal_mine_title",
+    desc: "city_coal_mine_desc",
+    effect: Some(|_| loc!("city_coal_mine_effect")),
+    cost: Some(|game| {
+        cost! {
+            Lumber => scale(80.0, 1.3, game.city.coal_mine),
+            WroughtIron => scale(5.0, 1.3, game.city.coal_mine)
+        }
+    }),
+    action: |s, game| {
+        if s.pay(game) {
+            inc!(game.city.coal_mine);
+        }
+    },
+    count: Some(|game| game.city.coal_mine),
+};
+
+pub const ACTION_OIL_WELL: Action = Action {
+    id: "city-oil_well",
+    title: "city_oil_well_title",
+    desc: "city_oil_well_desc",
+    effect: Some(|_| loc!("city_oil_well_effect")),
+    cost: Some(|game| {
+        cost! {
+            Stone => scale(100.0, 1.5, game.city.oil_well),
+            SheetMetal => scale(5.0, 1.5, game.city.oil_well)
+        }
+    }),
+    action: |s, game| {
+        if s.pay(game) {
+            inc!(game.city.oil_well);
+        }
+    },
+    count: Some(|game| game.city.oil_well),
+};
+
+pub const ACTION_COAL_POWER: Action = Action {
+    id: "city-coal_power",
+    title: "city_coal_power_title",
+    desc: "city_coal_power_desc",
+    effect: Some(|_| loc!("city_coal_power_effect")),
+    cost: Some(|game| {
+        cost! {
+            Brick => scale(30.0, 1.35, game.city.coal_power),
+            WroughtIron => scale(20.0, 1.35, game.city.coal_power)
+        }
+    }),
+    action: |s, game| {
+        if s.pay(game) {
+            inc!(game.city.coal_power);
+        }
+    },
+    count: Some(|game| game.city.coal_power),
+};
+
+pub const ACTION_OIL_POWER: Action = Action {
+    id: "city-oil_power",
+    title: "city_oil_power_title",
+    desc: "city_oil_power_desc",
+    effect: Some(|_| loc!("city_oil_power_effect")),
+    cost: Some(|game| {
+        cost! {
+            Brick => scale(50.0, 1.35, game.city.oil_power),
+            SheetMetal => scale(20.0, 1.35, game.city.oil_power)
+        }
+    }),
+    action: |s, game| {
+        if s.pay(game) {
+            inc!(game.city.oil_power);
+        }
+    },
+    count: Some(|game| game.city.oil_power),
+};
+
+pub const ACTION_FISSION_POWER: Action = Action {
+    id: "city-fission_power",
+    title: "city_fission_power_title",
+    desc: "city_fission_power_desc",
+    effect: Some(|_| loc!("city_fission_power_effect")),
+    cost: Some(|game| {
+        cost! {
+            Brick => scale(200.0, 1.4, game.city.fission_power),
+            SheetMetal => scale(100.0, 1.4, game.city.fission_power)
+        }
+    }),
+    action: |s, game| {
+        if s.pay(game) {
+            inc!(game.city.fission_power);
+        }
+    },
+    count: Some(|game| game.city.fission_power),
+};
+
+/// Debug cheat that throws the current run away
+#[cfg(debug_assertions)]
+pub const ACTION_RESET_SAVE: Action = Action {
+    id: "cheat-reset_save",
+    title: "ui_cheat_reset",
+    desc: "cheat_reset_desc",
+    effect: None,
+    cost: None,
+    action: |_, game| game.new_run(),
     count: None,
 };
+
+/////////////////////////////////////////////////////////////
+
+/// Every action outside of the tech tree, see [`find`]
+pub const EVERY_ACTION: &[Action] = &[
+    ACTION_RNA,
+    ACTION_DNA,
+    ACTION_MEMBRANE,
+    ACTION_ORGANELLES,
+    ACTION_NUCLEUS,
+    ACTION_EUKARYOTIC_CELL,
+    ACTION_MITOCHONDRIA,
+    ACTION_SEXUAL_REPRODUCTION,
+    ACTION_PHAGOCYTOSIS,
+    ACTION_CHLOROPLASTS,
+    ACTION_CHITIN,
+    ACTION_MULTICELLULAR,
+    ACTION_BILATERAL_SYMMETRY,
+    ACTION_POKILOHYDRIC,
+    ACTION_SPORES,
+    ACTION_BRYOPHYTE,
+    ACTION_SENTIENCE,
+    ACTION_FOOD,
+    ACTION_LUMBER,
+    ACTION_STONE,
+    ACTION_KNOWLEDGE,
+    ACTION_BASIC_HOUSING,
+    ACTION_FARM,
+    ACTION_UNIVERSITY,
+    ACTION_MINE,
+    ACTION_FOUNDRY,
+    ACTION_SHED,
+    ACTION_SILO,
+    ACTION_WAREHOUSE,
+    ACTION_TRADE_POST,
+    ACTION_COAL_MINE,
+    ACTION_OIL_WELL,
+    ACTION_COAL_POWER,
+    ACTION_OIL_POWER,
+    ACTION_FISSION_POWER,
+    prestige::ACTION_MAD,
+    #[cfg(debug_assertions)]
+    ACTION_RESET_SAVE,
+];
+
+/// Looks an action or tech up by its id, like the ids the stats are kept under
+pub fn find(id: &str) -> Option<&'static Action> {
+    EVERY_ACTION
+        .iter()
+        .chain(TECHS.iter().map(|research| &research.action))
+        .find(|action| action.id == id)
+}
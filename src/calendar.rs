@@ -0,0 +1,138 @@
+use crate::civic::Job;
+use crate::loc;
+use fastrand::Rng;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use strum::EnumIter;
+
+/// Days in a year until a planet is picked, a year is four equally long seasons
+pub const DEFAULT_ORBIT: u32 = 360;
+
+#[derive(Debug, Clone, Copy, PartialEq, EnumIter)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl Season {
+    /// Translation key of the title
+    pub fn key(&self) -> &'static str {
+        match self {
+            Season::Spring => "season_spring",
+            Season::Summer => "season_summer",
+            Season::Autumn => "season_autumn",
+            Season::Winter => "season_winter",
+        }
+    }
+
+    pub fn title(&self) -> Cow<'static, str> {
+        loc!(self.key())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, EnumIter, Serialize, Deserialize)]
+pub enum Weather {
+    Sunny,
+    Rain,
+    Snow,
+    Wind,
+}
+
+impl Weather {
+    /// Translation key of the title
+    pub fn key(&self) -> &'static str {
+        match self {
+            Weather::Sunny => "weather_sunny",
+            Weather::Rain => "weather_rain",
+            Weather::Snow => "weather_snow",
+            Weather::Wind => "weather_wind",
+        }
+    }
+
+    pub fn title(&self) -> Cow<'static, str> {
+        loc!(self.key())
+    }
+
+    /// Short symbol shown in front of the weather in the menu bar
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Weather::Sunny => "(o)",
+            Weather::Rain => "///",
+            Weather::Snow => "***",
+            Weather::Wind => "~~~",
+        }
+    }
+
+    /// Rolls the weather for a day in `season`, snow only falls in winter and rain only outside of it
+    fn roll(rng: &Rng, season: Season) -> Self {
+        let roll = rng.u32(0..10);
+        match (season, roll) {
+            (_, 0..=5) => Weather::Sunny,
+            (_, 6..=7) => Weather::Wind,
+            (Season::Winter, _) => Weather::Snow,
+            _ => Weather::Rain,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Calendar {
+    /// Day of the year, starting at 1
+    pub day: u32,
+    pub year: u32,
+    pub weather: Weather,
+    /// Days in a year, taken from the planet's orbit
+    pub orbit: u32,
+}
+
+impl Calendar {
+    pub fn new() -> Self {
+        Self {
+            day: 1,
+            year: 1,
+            weather: Weather::Sunny,
+            orbit: DEFAULT_ORBIT,
+        }
+    }
+
+    pub fn season(&self) -> Season {
+        match (self.day - 1) * 4 / self.orbit {
+            0 => Season::Spring,
+            1 => Season::Summer,
+            2 => Season::Autumn,
+            _ => Season::Winter,
+        }
+    }
+
+    /// Moves on to the next day and rolls its weather
+    pub fn advance(&mut self, rng: &Rng) {
+        self.day += 1;
+        if self.day > self.orbit {
+            self.day = 1;
+            self.year += 1;
+        }
+        self.weather = Weather::roll(rng, self.season());
+    }
+
+    /// Multiplier the season and weather apply to the output of a job
+    pub fn job_mult(&self, job: Job) -> f32 {
+        let season = match (self.season(), job) {
+            (Season::Spring, Job::Farmer) => 1.1,
+            (Season::Summer, Job::Farmer) => 1.2,
+            (Season::Winter, Job::Farmer) => 0.6,
+            (Season::Winter, Job::Lumberjack | Job::QuarryWorker) => 0.9,
+            _ => 1.0,
+        };
+        let weather = match (self.weather, job) {
+            (Weather::Rain, Job::Farmer) => 1.1,
+            (Weather::Snow, Job::Farmer | Job::Lumberjack | Job::QuarryWorker) => 0.8,
+            (Weather::Wind, Job::Lumberjack) => 1.1,
+            (Weather::Sunny, Job::Farmer) => 1.05,
+            _ => 1.0,
+        };
+
+        season * weather
+    }
+}
@@ -0,0 +1,105 @@
+use crate::loc;
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, collections::HashSet};
+use strum::{EnumIter, IntoEnumIterator};
+
+/// Share of every stockpiled raw resource lost per second under the decay challenge
+const DECAY: f32 = 0.005;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, Serialize, Deserialize)]
+pub enum Challenge {
+    NoCrafting,
+    NoTrade,
+    WeakMastery,
+    Joyless,
+    Decay,
+}
+
+impl Challenge {
+    pub fn iter() -> impl Iterator<Item = Challenge> {
+        <Self as IntoEnumIterator>::iter()
+    }
+
+    /// Translation key of the title
+    pub fn title_key(&self) -> &'static str {
+        match self {
+            Challenge::NoCrafting => "challenge_no_crafting_title",
+            Challenge::NoTrade => "challenge_no_trade_title",
+            Challenge::WeakMastery => "challenge_weak_mastery_title",
+            Challenge::Joyless => "challenge_joyless_title",
+            Challenge::Decay => "challenge_decay_title",
+        }
+    }
+
+    pub fn title(&self) -> Cow<'static, str> {
+        loc!(self.title_key())
+    }
+
+    /// Translation key of the description
+    pub fn desc_key(&self) -> &'static str {
+        match self {
+            Challenge::NoCrafting => "challenge_no_crafting_desc",
+            Challenge::NoTrade => "challenge_no_trade_desc",
+            Challenge::WeakMastery => "challenge_weak_mastery_desc",
+            Challenge::Joyless => "challenge_joyless_desc",
+            Challenge::Decay => "challenge_decay_desc",
+        }
+    }
+
+    pub fn description(&self) -> Cow<'static, str> {
+        loc!(self.desc_key())
+    }
+}
+
+/// The rules of a run, every system that a challenge changes asks this instead of checking challenges itself
+#[derive(Default, Serialize, Deserialize)]
+pub struct Rules {
+    pub challenges: HashSet<Challenge>,
+}
+
+impl Rules {
+    pub fn active(&self, challenge: Challenge) -> bool {
+        self.challenges.contains(&challenge)
+    }
+
+    pub fn toggle(&mut self, challenge: Challenge) {
+        if !self.challenges.remove(&challenge) {
+            self.challenges.insert(challenge);
+        }
+    }
+
+    pub fn crafting(&self) -> bool {
+        !self.active(Challenge::NoCrafting)
+    }
+
+    pub fn trade(&self) -> bool {
+        !self.active(Challenge::NoTrade)
+    }
+
+    /// Multiplier applied to the bonuses granted by achievements
+    pub fn mastery(&self) -> f32 {
+        if self.active(Challenge::WeakMastery) {
+            0.5
+        } else {
+            1.0
+        }
+    }
+
+    /// Highest morale the citizens can reach
+    pub fn max_morale(&self) -> f32 {
+        if self.active(Challenge::Joyless) {
+            100.0
+        } else {
+            150.0
+        }
+    }
+
+    /// Share of stockpiled raw resources lost every second
+    pub fn decay(&self) -> f32 {
+        if self.active(Challenge::Decay) {
+            DECAY
+        } else {
+            0.0
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct City {
+    pub basic_housing: Option<u32>,
+    pub farm: Option<u32>,
+    pub university: Option<u32>,
+    pub mine: Option<u32>,
+    pub foundry: Option<u32>,
+    pub shed: Option<u32>,
+    pub silo: Option<u32>,
+    pub warehouse: Option<u32>,
+    pub trade_post: Option<u32>,
+    pub coal_mine: Option<u32>,
+    pub oil_well: Option<u32>,
+    pub coal_power: Option<u32>,
+    pub oil_power: Option<u32>,
+    pub fission_power: Option<u32>,
+}
+
+impl City {
+    pub fn new() -> Self {
+        Self {
+            basic_housing: None,
+            farm: None,
+            university: None,
+            mine: None,
+            foundry: None,
+            shed: None,
+            silo: None,
+            warehouse: None,
+            trade_post: None,
+            coal_mine: None,
+            oil_well: None,
+            coal_power: None,
+            oil_power: None,
+            fission_power: None,
+        }
+    }
+
+    pub fn max_citizens(&self) -> u32 {
+        self.basic_housing.unwrap_or(0)
+    }
+}
@@ -0,0 +1,313 @@
+use crate::city::City;
+use crate::loc;
+use crate::resource::ResourceType;
+use serde::{Deserialize, Serialize};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    ops::{Index, IndexMut},
+};
+use strum::{EnumIter, IntoEnumIterator};
+
+#[derive(Debug, Clone, Copy, EnumIter)]
+pub enum Job {
+    Farmer,
+    Lumberjack,
+    QuarryWorker,
+    Miner,
+    CoalMiner,
+    Professor,
+}
+
+impl Job {
+    pub fn iter() -> impl Iterator<Item = Job> {
+        <Self as IntoEnumIterator>::iter()
+    }
+
+    /// Translation key of the title
+    pub fn key(&self) -> &'static str {
+        match self {
+            Job::Farmer => "job_farmer_title",
+            Job::Lumberjack => "job_lumberjack_title",
+            Job::QuarryWorker => "job_quarry_worker_title",
+            Job::Miner => "job_miner_title",
+            Job::CoalMiner => "job_coal_miner_title",
+            Job::Professor => "job_professor_title",
+        }
+    }
+
+    pub fn title(&self) -> Cow<'static, str> {
+        loc!(self.key())
+    }
+
+    /// The resource a single worker produces and how much of it per second
+    pub fn output(&self) -> (ResourceType, f32) {
+        match self {
+            Job::Farmer => (ResourceType::Food, 0.82),
+            Job::Lumberjack => (ResourceType::Lumber, 1.0),
+            Job::QuarryWorker => (ResourceType::Stone, 0.6),
+            Job::Miner => (ResourceType::Iron, 0.25),
+            Job::CoalMiner => (ResourceType::Coal, 0.2),
+            Job::Professor => (ResourceType::Knowledge, 0.5),
+        }
+    }
+
+    /// How many workers the city has room for, `None` means unlimited
+    pub fn max(&self, city: &City) -> Option<u32> {
+        match self {
+            Job::Farmer => Some(city.farm.unwrap_or(0)),
+            Job::Miner => Some(city.mine.unwrap_or(0)),
+            Job::CoalMiner => Some(city.coal_mine.unwrap_or(0)),
+            Job::Professor => Some(city.university.unwrap_or(0)),
+            Job::Lumberjack | Job::QuarryWorker => None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Jobs {
+    pub farmer: Option<u32>,
+    pub lumberjack: Option<u32>,
+    pub quarry_worker: Option<u32>,
+    pub miner: Option<u32>,
+    pub coal_miner: Option<u32>,
+    pub professor: Option<u32>,
+}
+
+impl Index<Job> for Jobs {
+    type Output = Option<u32>;
+
+    fn index(&self, index: Job) -> &Self::Output {
+        match index {
+            Job::Farmer => &self.farmer,
+            Job::Lumberjack => &self.lumberjack,
+            Job::QuarryWorker => &self.quarry_worker,
+            Job::Miner => &self.miner,
+            Job::CoalMiner => &self.coal_miner,
+            Job::Professor => &self.professor,
+        }
+    }
+}
+
+impl IndexMut<Job> for Jobs {
+    fn index_mut(&mut self, index: Job) -> &mut Self::Output {
+        match index {
+            Job::Farmer => &mut self.farmer,
+            Job::Lumberjack => &mut self.lumberjack,
+            Job::QuarryWorker => &mut self.quarry_worker,
+            Job::Miner => &mut self.miner,
+            Job::CoalMiner => &mut self.coal_miner,
+            Job::Professor => &mut self.professor,
+        }
+    }
+}
+
+/// Seconds that have to pass after a revolution before the government can be changed again
+pub const GOVERNMENT_COOLDOWN: u32 = 300;
+
+#[derive(Debug, Clone, Copy, PartialEq, EnumIter, Serialize, Deserialize)]
+pub enum Government {
+    Anarchy,
+    Autocracy,
+    Democracy,
+    Oligarchy,
+    Theocracy,
+    Technocracy,
+}
+
+impl Government {
+    pub fn iter() -> impl Iterator<Item = Government> {
+        <Self as IntoEnumIterator>::iter()
+    }
+
+    /// Translation key of the title
+    pub fn title_key(&self) -> &'static str {
+        match self {
+            Government::Anarchy => "govern_anarchy_title",
+            Government::Autocracy => "govern_autocracy_title",
+            Government::Democracy => "govern_democracy_title",
+            Government::Oligarchy => "govern_oligarchy_title",
+            Government::Theocracy => "govern_theocracy_title",
+            Government::Technocracy => "govern_technocracy_title",
+        }
+    }
+
+    pub fn title(&self) -> Cow<'static, str> {
+        loc!(self.title_key())
+    }
+
+    /// Translation key of the description
+    pub fn desc_key(&self) -> &'static str {
+        match self {
+            Government::Anarchy => "govern_anarchy_desc",
+            Government::Autocracy => "govern_autocracy_desc",
+            Government::Democracy => "govern_democracy_desc",
+            Government::Oligarchy => "govern_oligarchy_desc",
+            Government::Theocracy => "govern_theocracy_desc",
+            Government::Technocracy => "govern_technocracy_desc",
+        }
+    }
+
+    pub fn description(&self) -> Cow<'static, str> {
+        loc!(self.desc_key())
+    }
+
+    /// Multiplier applied to everything the city produces
+    pub fn production(&self) -> f32 {
+        match self {
+            Government::Autocracy => 1.1,
+            Government::Democracy => 0.95,
+            _ => 1.0,
+        }
+    }
+
+    /// Multiplier applied to Knowledge on top of `production`
+    pub fn knowledge(&self) -> f32 {
+        match self {
+            Government::Theocracy => 0.75,
+            Government::Technocracy => 1.1,
+            _ => 1.0,
+        }
+    }
+
+    /// Multiplier applied to tax income, anarchists don't pay taxes
+    pub fn taxes(&self) -> f32 {
+        match self {
+            Government::Anarchy => 0.0,
+            Government::Oligarchy => 1.25,
+            Government::Technocracy => 0.9,
+            _ => 1.0,
+        }
+    }
+
+    /// Flat bonus added to morale
+    pub fn morale(&self) -> f32 {
+        match self {
+            Government::Anarchy => -5.0,
+            Government::Autocracy => -5.0,
+            Government::Democracy => 5.0,
+            Government::Oligarchy => -2.0,
+            Government::Theocracy => 10.0,
+            Government::Technocracy => 0.0,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Civic {
+    pub citizens: u32,
+    pub jobs: Jobs,
+    /// Craftsmen assigned to each crafted resource
+    pub crafters: HashMap<ResourceType, u32>,
+    pub government: Government,
+    /// Seconds left until the government can be changed, `None` until the government tech is researched
+    pub government_cooldown: Option<u32>,
+    /// Percentage of income taken as taxes, `None` until currency is researched
+    pub tax_rate: Option<u32>,
+    pub morale: f32,
+    /// Set while the food is gone, running out is only reported once until there's food again
+    pub starving: bool,
+}
+
+impl Civic {
+    pub fn new() -> Self {
+        Self {
+            citizens: 0,
+            jobs: Jobs {
+                farmer: None,
+                lumberjack: None,
+                quarry_worker: None,
+                miner: None,
+                coal_miner: None,
+                professor: None,
+            },
+            crafters: HashMap::new(),
+            government: Government::Anarchy,
+            government_cooldown: None,
+            tax_rate: None,
+            morale: 100.0,
+            starving: false,
+        }
+    }
+
+    /// Replaces the government and starts the revolution cooldown
+    pub fn set_government(&mut self, government: Government) {
+        if self.government_cooldown == Some(0) && self.government != government {
+            self.government = government;
+            self.government_cooldown = Some(GOVERNMENT_COOLDOWN);
+        }
+    }
+
+    /// Recalculates morale from the government and the tax rate, clamped between 50% and `max`
+    pub fn update_morale(&mut self, max: f32) {
+        let mut morale = 100.0 + self.government.morale();
+        if let Some(rate) = self.tax_rate {
+            morale += 20.0 - rate as f32;
+        }
+        self.morale = morale.clamp(50.0, max);
+    }
+
+    /// Production multiplier granted by morale, morale above 100% only counts for half
+    pub fn morale_mult(&self) -> f32 {
+        if self.morale < 100.0 {
+            self.morale / 100.0
+        } else {
+            1.0 + (self.morale - 100.0) / 200.0
+        }
+    }
+
+    pub fn employed(&self) -> u32 {
+        Job::iter().filter_map(|job| self.jobs[job]).sum::<u32>() + self.craftsmen()
+    }
+
+    pub fn craftsmen(&self) -> u32 {
+        self.crafters.values().sum()
+    }
+
+    pub fn assign_crafter(&mut self, res: ResourceType, max: u32) {
+        if self.unemployed() > 0 && self.craftsmen() < max {
+            *self.crafters.entry(res).or_default() += 1;
+        }
+    }
+
+    pub fn unassign_crafter(&mut self, res: ResourceType) {
+        if let Some(count) = self.crafters.get_mut(&res) {
+            *count = count.saturating_sub(1);
+        }
+    }
+
+    pub fn unemployed(&self) -> u32 {
+        self.citizens.saturating_sub(self.employed())
+    }
+
+    pub fn assign(&mut self, job: Job, max: Option<u32>) {
+        let unemployed = self.unemployed();
+        if let Some(count) = self.jobs[job].as_mut()
+            && unemployed > 0
+            && max.map_or(true, |max| *count < max)
+        {
+            *count += 1;
+        }
+    }
+
+    pub fn unassign(&mut self, job: Job) {
+        if let Some(count) = self.jobs[job].as_mut() {
+            *count = count.saturating_sub(1);
+        }
+    }
+
+    /// Removes a citizen, taking them from the unemployed before anyone with a job and craftsmen last
+    pub fn starve(&mut self) {
+        if self.citizens == 0 {
+            return;
+        }
+        if self.unemployed() == 0 {
+            if let Some(job) = Job::iter().find(|&job| self.jobs[job].unwrap_or(0) > 0) {
+                self.unassign(job);
+            } else if let Some(count) = self.crafters.values_mut().find(|count| **count > 0) {
+                *count -= 1;
+            }
+        }
+        self.citizens -= 1;
+    }
+}
@@ -0,0 +1,297 @@
+use crate::achievement::ACHIEVEMENTS;
+use crate::action::EVERY_ACTION;
+use crate::calendar::{Season, Weather};
+use crate::challenge::Challenge;
+use crate::civic::{Government, Job};
+use crate::fonts::Glyphs;
+use crate::lang;
+use crate::message::MessageKind;
+use crate::planet::Biome;
+use crate::race::Species;
+use crate::resource::ResourceType;
+use crate::settings::Notation;
+use crate::tech::TECHS;
+use crate::theme::Theme;
+use crate::universe::Universe;
+use fluent_syntax::ast::{Entry, Expression, InlineExpression, Pattern, PatternElement};
+use std::collections::{BTreeMap, BTreeSet};
+use strum::IntoEnumIterator;
+
+/// The sources are scanned for `loc!` and `msg!` calls, so the check only works from a checkout, which is why it's
+/// left out of release builds
+const SRC_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+
+/// A key the code looks up and the arguments it's formatted with
+struct Reference {
+    key: String,
+    args: BTreeSet<String>,
+    /// `file:line` the key is used at, or the [`DYNAMIC`] entry it comes from
+    location: String,
+}
+
+type Keys = fn() -> Vec<&'static str>;
+
+/// Keys the code picks at runtime instead of naming them in a `loc!` or `msg!` call, by where they come from.
+/// Every table and enum handing out keys has to be listed here, or its keys show up as unused
+const DYNAMIC: &[(&str, Keys)] = &[
+    ("actions", || {
+        EVERY_ACTION
+            .iter()
+            .flat_map(|action| [action.title, action.desc])
+            .collect()
+    }),
+    ("techs", || {
+        TECHS
+            .iter()
+            .flat_map(|research| [research.action.title, research.action.desc])
+            .collect()
+    }),
+    ("achievements", || {
+        ACHIEVEMENTS
+            .iter()
+            .flat_map(|achievement| [achievement.title, achievement.desc])
+            .collect()
+    }),
+    ("resources", || ResourceType::iter().map(|res| res.key()).collect()),
+    ("species", || Species::iter().map(|species| species.key()).collect()),
+    ("jobs", || Job::iter().map(|job| job.key()).collect()),
+    ("governments", || {
+        Government::iter()
+            .flat_map(|government| [government.title_key(), government.desc_key()])
+            .collect()
+    }),
+    ("seasons", || Season::iter().map(|season| season.key()).collect()),
+    ("weather", || Weather::iter().map(|weather| weather.key()).collect()),
+    ("biomes", || {
+        Biome::ALL
+            .iter()
+            .flat_map(|biome| [biome.title_key(), biome.desc_key()])
+            .collect()
+    }),
+    ("universes", || {
+        Universe::iter()
+            .flat_map(|universe| [universe.title_key(), universe.desc_key()])
+            .collect()
+    }),
+    ("challenges", || {
+        Challenge::iter()
+            .flat_map(|challenge| [challenge.title_key(), challenge.desc_key()])
+            .collect()
+    }),
+    ("message kinds", || MessageKind::iter().map(|kind| kind.key()).collect()),
+    ("notations", || {
+        Notation::iter().map(|notation| notation.key()).collect()
+    }),
+    ("glyph ranges", || Glyphs::iter().map(|glyphs| glyphs.key()).collect()),
+    ("themes", || Theme::builtin().iter().filter_map(Theme::key).collect()),
+];
+
+/// Every key the code uses, from `loc!` and `msg!` calls with a literal key and from [`DYNAMIC`]
+fn references() -> Vec<Reference> {
+    let mut files: Vec<_> = std::fs::read_dir(SRC_DIR)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| path.extension().is_some_and(|extension| extension == "rs"))
+        .collect();
+    files.sort();
+
+    let mut refs = Vec::new();
+    for path in files {
+        if let Ok(source) = std::fs::read_to_string(&path) {
+            let file = path.file_name().unwrap_or_default().to_string_lossy();
+            scan(&file, &source, &mut refs);
+        }
+    }
+
+    for (source, keys) in DYNAMIC {
+        refs.extend(keys().into_iter().map(|key| Reference {
+            key: key.to_string(),
+            args: BTreeSet::new(),
+            location: source.to_string(),
+        }));
+    }
+    refs
+}
+
+fn scan(file: &str, source: &str, refs: &mut Vec<Reference>) {
+    let mut push = |pos: usize, key: &str, args: BTreeSet<String>| {
+        let line = source[..pos].matches('\n').count() + 1;
+        refs.push(Reference {
+            key: key.to_string(),
+            args,
+            location: format!("{file}:{line}"),
+        });
+    };
+    let literal = |start: usize| source[start..].find('"').map(|len| &source[start..start + len]);
+
+    let calls = source.match_indices("loc!(\"").chain(source.match_indices("msg!(\""));
+    for (pos, pattern) in calls {
+        let start = pos + pattern.len();
+        if let Some(key) = literal(start) {
+            // Arguments are either bare identifiers or `"name" = value`
+            let args = split_args(&source[start + key.len() + 1..])
+                .into_iter()
+                .map(|arg| match arg.split_once('=') {
+                    Some((name, _)) => name.trim().trim_matches('"').to_string(),
+                    None => arg,
+                })
+                .collect();
+            push(pos, key, args);
+        }
+    }
+}
+
+/// Splits the rest of a macro call into its arguments, up to the parenthesis that closes it
+fn split_args(rest: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0;
+    for c in rest.chars() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' if depth == 0 => break,
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                args.push(std::mem::take(&mut current));
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    args.push(current);
+
+    args.into_iter()
+        .map(|arg| arg.trim().to_string())
+        .filter(|arg| !arg.is_empty())
+        .collect()
+}
+
+/// Every message of a locale with the variables it uses
+fn messages(locale: &str, errors: &mut Vec<String>) -> BTreeMap<String, BTreeSet<String>> {
+    let mut messages = BTreeMap::new();
+    for (source, stuff) in lang::sources(locale) {
+        let res = lang::parse(&source, stuff, errors);
+        for entry in res.entries() {
+            if let Entry::Message(message) = entry {
+                let mut vars = BTreeSet::new();
+                let attributes = message.attributes.iter().map(|attribute| &attribute.value);
+                for pattern in message.value.iter().chain(attributes) {
+                    pattern_variables(pattern, &mut vars);
+                }
+                messages.insert(message.id.name.to_string(), vars);
+            }
+        }
+    }
+    messages
+}
+
+fn pattern_variables(pattern: &Pattern<&str>, vars: &mut BTreeSet<String>) {
+    for element in &pattern.elements {
+        if let PatternElement::Placeable { expression } = element {
+            expression_variables(expression, vars);
+        }
+    }
+}
+
+fn expression_variables(expression: &Expression<&str>, vars: &mut BTreeSet<String>) {
+    match expression {
+        Expression::Select { selector, variants } => {
+            inline_variables(selector, vars);
+            for variant in variants {
+                pattern_variables(&variant.value, vars);
+            }
+        }
+        Expression::Inline(inline) => inline_variables(inline, vars),
+    }
+}
+
+fn inline_variables(inline: &InlineExpression<&str>, vars: &mut BTreeSet<String>) {
+    match inline {
+        InlineExpression::VariableReference { id } => {
+            vars.insert(id.name.to_string());
+        }
+        InlineExpression::FunctionReference { arguments, .. } => {
+            for arg in &arguments.positional {
+                inline_variables(arg, vars);
+            }
+            for arg in &arguments.named {
+                inline_variables(&arg.value, vars);
+            }
+        }
+        InlineExpression::Placeable { expression } => expression_variables(expression, vars),
+        _ => {}
+    }
+}
+
+/// Every locale with the keys it's missing, the keys nothing uses and the keys formatted with other arguments
+/// than the message expects
+pub fn problems() -> Vec<(String, Vec<String>)> {
+    if std::fs::read_dir(SRC_DIR).is_err() {
+        let problem = format!("the sources are expected in {SRC_DIR}");
+        return vec![(SRC_DIR.to_string(), vec![problem])];
+    }
+    let refs = references();
+    let used: BTreeSet<&str> = refs.iter().map(|reference| reference.key.as_str()).collect();
+
+    // The pseudo locale is generated from the default one, so it has nothing of its own to check
+    let locales = lang::locales()
+        .into_iter()
+        .filter(|locale| locale != lang::PSEUDO_LOCALE);
+    locales
+        .map(|locale| {
+            let mut problems = Vec::new();
+            let messages = messages(&locale, &mut problems);
+
+            let mut missing = BTreeMap::new();
+            for reference in &refs {
+                match messages.get(&reference.key) {
+                    None => {
+                        missing.entry(&reference.key).or_insert(&reference.location);
+                    }
+                    Some(vars) if *vars != reference.args => problems.push(format!(
+                        "mismatched {}: {} passes {:?}, the message uses {:?}",
+                        reference.key, reference.location, reference.args, vars
+                    )),
+                    Some(_) => {}
+                }
+            }
+            for (key, location) in missing {
+                problems.push(format!("missing {key}: used at {location}"));
+            }
+            for key in messages.keys().filter(|key| !used.contains(key.as_str())) {
+                problems.push(format!("unused {key}"));
+            }
+
+            (locale, problems)
+        })
+        .collect()
+}
+
+/// Prints the [`problems`] of every locale, returns whether every locale came out clean
+pub fn check() -> bool {
+    let mut clean = true;
+    for (locale, problems) in problems() {
+        println!("{locale}: {} problems", problems.len());
+        for problem in &problems {
+            println!("    {problem}");
+        }
+        clean &= problems.is_empty();
+    }
+    clean
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locales_are_complete() {
+        let problems: Vec<String> = problems()
+            .into_iter()
+            .flat_map(|(locale, problems)| problems.into_iter().map(move |problem| format!("{locale}: {problem}")))
+            .collect();
+        assert!(problems.is_empty(), "{}", problems.join("\n"));
+    }
+}
@@ -0,0 +1,160 @@
+use crate::action::ACTION_FOUNDRY;
+use crate::power;
+use crate::race::Species;
+use crate::resource::{Cost, ResourceType, ResourceType::*};
+use crate::tech::{TECH_FOUNDRY, TECH_METAL_WORKING, TECH_STORAGE, TECH_WAREHOUSE};
+use crate::{stats, Game};
+
+/// Crafts a single craftsman completes every second
+const CRAFT_SPEED: f32 = 0.1;
+
+/// Turns raw materials into a refined resource
+pub struct Recipe {
+    pub output: ResourceType,
+    pub inputs: &'static [Cost],
+    /// The tech that has to be researched before the recipe can be crafted
+    pub tech: &'static str,
+    /// Extra output for species that are good at this craft
+    pub bonus: &'static [(Species, f32)],
+}
+
+impl Recipe {
+    pub fn available(&self, game: &Game) -> bool {
+        game.rules.crafting() && game.tech.researched(self.tech)
+    }
+
+    /// Multiplier applied to the output when crafted by `species`
+    pub fn bonus(&self, species: Species) -> f32 {
+        let bonus: f32 = self.bonus.iter().filter(|(s, _)| *s == species).map(|(_, b)| b).sum();
+        1.0 + bonus
+    }
+
+    /// How many crafts the stockpile can pay for and the output has room for
+    pub fn affordable(&self, game: &Game) -> u32 {
+        let output = &game.resources[self.output];
+        let room = if output.max == -1.0 {
+            u32::MAX
+        } else {
+            (output.max - output.amount).max(0.0).floor() as u32
+        };
+
+        self.inputs
+            .iter()
+            .map(|input| (game.resources[input.resource].amount / input.amount).floor() as u32)
+            .fold(room, u32::min)
+    }
+}
+
+pub const RECIPES: &[Recipe] = &[
+    Recipe {
+        output: Plywood,
+        inputs: &[Cost {
+            resource: Lumber,
+            amount: 10.0,
+        }],
+        tech: TECH_FOUNDRY.id,
+        bonus: &[(Species::Entish, 0.25)],
+    },
+    Recipe {
+        output: Brick,
+        inputs: &[Cost {
+            resource: Stone,
+            amount: 8.0,
+        }],
+        tech: TECH_FOUNDRY.id,
+        bonus: &[(Species::Cacti, 0.25)],
+    },
+    Recipe {
+        output: WroughtIron,
+        inputs: &[Cost {
+            resource: Iron,
+            amount: 8.0,
+        }],
+        tech: TECH_METAL_WORKING.id,
+        bonus: &[(Species::Sporgar, 0.25)],
+    },
+    Recipe {
+        output: SheetMetal,
+        inputs: &[
+            Cost {
+                resource: Iron,
+                amount: 12.0,
+            },
+            Cost {
+                resource: WroughtIron,
+                amount: 1.0,
+            },
+        ],
+        tech: TECH_METAL_WORKING.id,
+        bonus: &[(Species::Molding, 0.25)],
+    },
+    Recipe {
+        output: Crates,
+        inputs: &[Cost {
+            resource: Plywood,
+            amount: 10.0,
+        }],
+        tech: TECH_STORAGE.id,
+        bonus: &[],
+    },
+    Recipe {
+        output: Containers,
+        inputs: &[Cost {
+            resource: SheetMetal,
+            amount: 8.0,
+        }],
+        tech: TECH_WAREHOUSE.id,
+        bonus: &[],
+    },
+];
+
+/// Crafts `times` by hand, or as many times as can be afforded when `None`
+pub fn craft(game: &mut Game, recipe: &Recipe, times: Option<u32>) {
+    if !recipe.available(game) {
+        return;
+    }
+
+    let affordable = recipe.affordable(game);
+    let times = times.unwrap_or(affordable);
+    if times == 0 || times > affordable {
+        return;
+    }
+
+    for input in recipe.inputs {
+        game.mod_res(input.resource, -input.amount * times as f32, true, false);
+        stats::spend(game, input.resource, input.amount * times as f32);
+    }
+    let output = times as f32 * recipe.bonus(game.race.species);
+    game.mod_res(recipe.output, output, true, false);
+}
+
+/// Lets every assigned craftsman work, stalling once the inputs run out
+pub fn auto_craft(game: &mut Game, time_mult: f32) {
+    // Every craftsman works in a foundry, the ones in running foundries are handed out in recipe order
+    let mut powered = power::powered(game, ACTION_FOUNDRY.id, game.civic.crafters.values().sum());
+    for recipe in RECIPES {
+        let crafters = game.civic.crafters.get(&recipe.output).copied().unwrap_or(0);
+        if crafters == 0 || !recipe.available(game) || game.resources[recipe.output].is_full() {
+            continue;
+        }
+
+        let boosted = crafters.min(powered);
+        powered -= boosted;
+
+        let staff = crafters as f32 + boosted as f32 * power::POWERED_BOOST;
+        let mut volume = staff * CRAFT_SPEED * time_mult;
+        for input in recipe.inputs {
+            volume = volume.min(game.resources[input.resource].amount / input.amount);
+        }
+        if volume <= 0.0 {
+            continue;
+        }
+
+        for input in recipe.inputs {
+            game.mod_res(input.resource, -input.amount * volume, false, false);
+            stats::spend(game, input.resource, input.amount * volume);
+        }
+        let output = volume * recipe.bonus(game.race.species) * game.global_mult();
+        game.mod_res(recipe.output, output, false, false);
+    }
+}
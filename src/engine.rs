@@ -1,4 +1,9 @@
-use crate::{clockwork::Clockwork, Game};
+use crate::{
+    clockwork::Clockwork,
+    fonts,
+    settings::{self, Settings},
+    Game,
+};
 use glow::HasContext;
 use glutin::{
     dpi::LogicalSize,
@@ -7,10 +12,13 @@ use glutin::{
     window::WindowBuilder,
     ContextBuilder, PossiblyCurrent, WindowedContext,
 };
-use imgui::FontSource;
-use imgui_glow_renderer::AutoRenderer;
+use imgui::ConfigFlags;
+use imgui_glow_renderer::{AutoRenderer, TextureMap};
 use imgui_winit_support::{HiDpiMode, WinitPlatform};
-use std::time::{Duration, Instant};
+use std::{
+    path::PathBuf,
+    time::{Duration, Instant},
+};
 
 pub struct Engine {
     event_loop: EventLoop<()>,
@@ -43,33 +51,28 @@ impl Engine {
         let mut imgui = imgui::Context::create();
         let mut platform = WinitPlatform::init(&mut imgui);
 
-        imgui.set_ini_filename(None);
-        imgui.fonts().add_font(&[FontSource::DefaultFontData {
-            config: Some(imgui::FontConfig {
-                oversample_h: 1,
-                pixel_snap_h: true,
-                size_pixels: (13.0 * platform.hidpi_factor()) as f32,
-                ..Default::default()
-            }),
-        }]);
+        imgui.set_ini_filename(Some(PathBuf::from(settings::LAYOUT_FILE)));
+        imgui.io_mut().config_flags |= ConfigFlags::DOCKING_ENABLE;
+        fonts::build(&mut imgui, &Settings::load(), platform.hidpi_factor());
         imgui.io_mut().font_global_scale = (1.0 / platform.hidpi_factor()) as f32;
 
         platform.attach_window(imgui.io_mut(), window.window(), HiDpiMode::Rounded);
 
         let gl = unsafe { glow::Context::from_loader_function(|s| window.get_proc_address(s).cast()) };
 
-        let mut engine = Self {
+        let mut clockwork = Clockwork::new();
+        Game::schedule(&mut clockwork);
+
+        Self {
             event_loop,
             window,
             platform,
             renderer: AutoRenderer::initialize(gl, &mut imgui).unwrap(),
             imgui,
             last_frame: Instant::now(),
-            clockwork: Clockwork::new(),
-            game: gemstone::mem::zero(),
-        };
-        engine.game = Game::new(&mut engine);
-        engine
+            clockwork,
+            game: Game::load_save().unwrap_or_else(Game::new),
+        }
     }
 
     pub fn run(self) -> ! {
@@ -96,9 +99,20 @@ impl Engine {
                     window.window().request_redraw();
                 }
                 Event::RedrawRequested(_) => {
+                    if game.rebuild_fonts {
+                        fonts::build(&mut imgui, &game.settings, platform.hidpi_factor());
+                        upload_fonts(&mut imgui, &mut renderer);
+                        game.rebuild_fonts = false;
+                    }
+
                     // The renderer assumes you'll be clearing the buffer yourself
                     unsafe { renderer.gl_context().clear(glow::COLOR_BUFFER_BIT) };
 
+                    game.settings.apply(&mut imgui, platform.hidpi_factor());
+                    if game.apply_theme {
+                        game.theme.apply(imgui.style_mut());
+                        game.apply_theme = false;
+                    }
                     let ui = imgui.frame();
                     game.update(ui);
 
@@ -129,3 +143,40 @@ impl Engine {
         });
     }
 }
+
+/// Uploads a rebuilt font atlas to a new texture on the renderer, freeing the texture it replaces
+fn upload_fonts(imgui: &mut imgui::Context, renderer: &mut AutoRenderer) {
+    let mut fonts = imgui.fonts();
+    let old = renderer.texture_map().gl_texture(fonts.tex_id);
+    let atlas = fonts.build_rgba32_texture();
+
+    let gl = renderer.gl_context();
+    let texture = unsafe {
+        let texture = gl.create_texture().expect("could not create font texture");
+        gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+        gl.tex_parameter_i32(glow::TEXTURE_2D, glow::TEXTURE_MIN_FILTER, glow::LINEAR as i32);
+        gl.tex_parameter_i32(glow::TEXTURE_2D, glow::TEXTURE_MAG_FILTER, glow::LINEAR as i32);
+        gl.tex_parameter_i32(glow::TEXTURE_2D, glow::TEXTURE_WRAP_S, glow::CLAMP_TO_EDGE as i32);
+        gl.tex_parameter_i32(glow::TEXTURE_2D, glow::TEXTURE_WRAP_T, glow::CLAMP_TO_EDGE as i32);
+        gl.tex_image_2d(
+            glow::TEXTURE_2D,
+            0,
+            glow::RGBA as i32,
+            atlas.width as i32,
+            atlas.height as i32,
+            0,
+            glow::RGBA,
+            glow::UNSIGNED_BYTE,
+            Some(atlas.data),
+        );
+        if let Some(old) = old {
+            gl.delete_texture(old);
+        }
+        texture
+    };
+
+    fonts.tex_id = renderer
+        .texture_map_mut()
+        .register(texture)
+        .expect("could not register font texture");
+}
@@ -0,0 +1,139 @@
+use crate::message::{Arg, MessageKind, Text};
+use crate::msg;
+use crate::race::Species;
+use crate::resource::ResourceType::*;
+use crate::Game;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Seconds between two rolls, events are rolled from the long loop
+pub const PERIOD: u32 = 5;
+/// One in this many rolls triggers an event
+const CHANCE: u32 = 12;
+
+pub struct Event {
+    pub id: &'static str,
+    /// Relative chance of being picked among every event that can currently happen
+    weight: u32,
+    /// Seconds that have to pass before the event can happen again
+    cooldown: u32,
+    condition: fn(&Game) -> bool,
+    /// Applies the event and returns the message logged for it
+    effect: fn(&mut Game) -> Text,
+}
+
+pub const EVENTS: &[Event] = &[
+    Event {
+        id: "meteor",
+        weight: 2,
+        cooldown: 600,
+        condition: |game| game.race.species != Species::Protoplasm,
+        effect: |game| {
+            let res = if game.resources.iron.display { Iron } else { Stone };
+            let amount = game.rng.u32(25..=100);
+            game.mod_res(res, amount as f32, false, false);
+            let resource = Arg::Key(res.key().to_string());
+            msg!("event_meteor", amount, resource)
+        },
+    },
+    Event {
+        id: "fire",
+        weight: 3,
+        cooldown: 300,
+        condition: |game| game.resources.lumber.amount >= 50.0,
+        effect: |game| {
+            let amount = (game.resources.lumber.amount * (0.1 + game.rng.f32() * 0.2)).floor() as u32;
+            game.mod_res(Lumber, -(amount as f32), false, false);
+            msg!("event_fire", amount)
+        },
+    },
+    Event {
+        id: "trade_boom",
+        weight: 2,
+        cooldown: 300,
+        condition: |game| game.rules.trade() && game.city.trade_post.unwrap_or(0) > 0,
+        effect: |game| {
+            // Drift pulls the prices back over time
+            for price in game.market.prices.values_mut() {
+                *price *= 1.25;
+            }
+            msg!("event_trade_boom")
+        },
+    },
+    Event {
+        id: "festival",
+        weight: 3,
+        cooldown: 600,
+        condition: |game| game.civic.citizens >= 10,
+        effect: |game| {
+            game.events.modifiers.insert("festival".to_string(), (1.15, 60));
+            msg!("event_festival")
+        },
+    },
+];
+
+#[derive(Serialize, Deserialize)]
+pub struct Events {
+    /// Seconds left before an event can happen again
+    pub cooldowns: HashMap<String, u32>,
+    /// Temporary production multipliers and how many seconds they still last
+    pub modifiers: HashMap<String, (f32, u32)>,
+}
+
+impl Events {
+    pub fn new() -> Self {
+        Self {
+            cooldowns: HashMap::new(),
+            modifiers: HashMap::new(),
+        }
+    }
+
+    /// Multiplier of every active temporary modifier
+    pub fn production(&self) -> f32 {
+        self.modifiers.values().map(|(mult, _)| mult).product()
+    }
+
+    /// Ticks cooldowns and modifiers down by `elapsed` seconds, dropping the ones that ran out
+    fn tick(&mut self, elapsed: u32) {
+        for cooldown in self.cooldowns.values_mut() {
+            *cooldown = cooldown.saturating_sub(elapsed);
+        }
+        self.cooldowns.retain(|_, cooldown| *cooldown > 0);
+
+        for (_, duration) in self.modifiers.values_mut() {
+            *duration = duration.saturating_sub(elapsed);
+        }
+        self.modifiers.retain(|_, (_, duration)| *duration > 0);
+    }
+}
+
+/// Ticks the running events and maybe triggers a new one, picked by weight among those that can happen
+pub fn roll(game: &mut Game) {
+    game.events.tick(PERIOD);
+
+    if game.rng.u32(0..CHANCE) != 0 {
+        return;
+    }
+
+    let possible: Vec<&Event> = EVENTS
+        .iter()
+        .filter(|event| !game.events.cooldowns.contains_key(event.id) && (event.condition)(game))
+        .collect();
+    let total: u32 = possible.iter().map(|event| event.weight).sum();
+    if total == 0 {
+        return;
+    }
+
+    let mut pick = game.rng.u32(0..total);
+    for event in possible {
+        if pick >= event.weight {
+            pick -= event.weight;
+            continue;
+        }
+
+        let message = (event.effect)(game);
+        game.events.cooldowns.insert(event.id.to_string(), event.cooldown);
+        game.log.push(MessageKind::Event, message);
+        break;
+    }
+}
@@ -0,0 +1,119 @@
+use crate::loc;
+use crate::settings::Settings;
+use imgui::{FontConfig, FontGlyphRanges, FontSource};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use strum::{EnumIter, IntoEnumIterator};
+
+/// Where TTF and OTF fonts are looked for
+pub const FONTS_DIR: &str = "assets/fonts";
+
+/// The set of glyphs baked into the font atlas, anything outside of it is drawn as a question mark
+#[derive(Debug, Clone, Copy, PartialEq, EnumIter, Serialize, Deserialize)]
+pub enum Glyphs {
+    Latin,
+    Cyrillic,
+    Japanese,
+    Korean,
+    ChineseCommon,
+    ChineseFull,
+    Thai,
+    Vietnamese,
+}
+
+impl Glyphs {
+    pub fn iter() -> impl Iterator<Item = Glyphs> {
+        <Self as IntoEnumIterator>::iter()
+    }
+
+    /// Translation key of the title
+    pub fn key(&self) -> &'static str {
+        match self {
+            Glyphs::Latin => "glyphs_latin",
+            Glyphs::Cyrillic => "glyphs_cyrillic",
+            Glyphs::Japanese => "glyphs_japanese",
+            Glyphs::Korean => "glyphs_korean",
+            Glyphs::ChineseCommon => "glyphs_chinese_common",
+            Glyphs::ChineseFull => "glyphs_chinese_full",
+            Glyphs::Thai => "glyphs_thai",
+            Glyphs::Vietnamese => "glyphs_vietnamese",
+        }
+    }
+
+    pub fn title(&self) -> Cow<'static, str> {
+        loc!(self.key())
+    }
+
+    /// Every range but latin also includes the latin glyphs
+    pub fn ranges(&self) -> FontGlyphRanges {
+        match self {
+            Glyphs::Latin => FontGlyphRanges::default(),
+            Glyphs::Cyrillic => FontGlyphRanges::cyrillic(),
+            Glyphs::Japanese => FontGlyphRanges::japanese(),
+            Glyphs::Korean => FontGlyphRanges::korean(),
+            Glyphs::ChineseCommon => FontGlyphRanges::chinese_simplified_common(),
+            Glyphs::ChineseFull => FontGlyphRanges::chinese_full(),
+            Glyphs::Thai => FontGlyphRanges::thai(),
+            Glyphs::Vietnamese => FontGlyphRanges::vietnamese(),
+        }
+    }
+}
+
+/// Every font file in [`FONTS_DIR`]
+pub fn available() -> Vec<String> {
+    let mut fonts: Vec<String> = std::fs::read_dir(FONTS_DIR)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+            if extension != "ttf" && extension != "otf" {
+                return None;
+            }
+            Some(path.file_name()?.to_str()?.to_string())
+        })
+        .collect();
+    fonts.sort();
+    fonts
+}
+
+fn read(name: &str) -> Option<Vec<u8>> {
+    std::fs::read(std::path::Path::new(FONTS_DIR).join(name)).ok()
+}
+
+/// Replaces the fonts in the atlas with the ones picked in the settings,
+/// the renderer has to upload the atlas again before it's drawn with
+pub fn build(imgui: &mut imgui::Context, settings: &Settings, hidpi: f64) {
+    let size_pixels = (settings.font_size as f64 * hidpi) as f32;
+    let config = || FontConfig {
+        oversample_h: 1,
+        pixel_snap_h: true,
+        size_pixels,
+        glyph_ranges: settings.glyphs.ranges(),
+        ..Default::default()
+    };
+
+    // A font that can't be read falls back to the built in one, which only has latin glyphs
+    let main = settings.font.as_deref().and_then(read);
+    let fallback = settings.fallback_font.as_deref().and_then(read);
+
+    let mut sources = vec![match &main {
+        Some(data) => FontSource::TtfData {
+            data,
+            size_pixels,
+            config: Some(config()),
+        },
+        None => FontSource::DefaultFontData { config: Some(config()) },
+    }];
+    // Merged into the main font, only filling in the glyphs it doesn't have
+    if let Some(data) = &fallback {
+        sources.push(FontSource::TtfData {
+            data,
+            size_pixels,
+            config: Some(config()),
+        });
+    }
+
+    imgui.fonts().clear();
+    imgui.fonts().add_font(&sources);
+}
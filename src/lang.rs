@@ -1,40 +1,164 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, sync::RwLock};
 
+use crate::settings::{Settings, DEFAULT_LOCALE};
 use fluent::{FluentArgs, FluentBundle, FluentResource};
 use once_cell::sync::Lazy;
-use unic_langid::langid;
+use unic_langid::LanguageIdentifier;
+
+/// Translations built into the binary, so the game runs from any working directory
+const EMBEDDED: &[(&str, &str)] = &[("en-US", include_str!("../lang/en-US.ftl"))];
+/// Translation files here are laid over the embedded ones, the keys they define win
+pub const LANG_DIR: &str = "lang";
+/// Generated from the default locale to test layouts, see [`pseudo`]
+pub const PSEUDO_LOCALE: &str = "en-XA";
+
+static LANG: Lazy<RwLock<Locale>> = Lazy::new(|| RwLock::new(Locale::new(&Settings::load().locale)));
+
+struct Locale {
+    /// Bundles to look keys up in, from the picked locale down to the default one
+    bundles: Vec<FluentBundle<FluentResource>>,
+    /// Problems found while reading the translation files, the entries that failed are left out
+    errors: Vec<String>,
+    /// Whether every message is bracketed, so text cut off at either end stands out
+    pseudo: bool,
+}
 
-static LANG: Lazy<Locale> = Lazy::new(Locale::new);
+impl Locale {
+    pub fn new(locale: &str) -> Self {
+        let pseudo = locale == PSEUDO_LOCALE;
+        let mut errors = Vec::new();
+        let bundles = chain(locale)
+            .into_iter()
+            .filter_map(|langid| {
+                let sources = sources(&langid.to_string());
+                if sources.is_empty() {
+                    return None;
+                }
+
+                let mut bundle = FluentBundle::new(vec![langid]);
+
+                for (source, stuff) in sources {
+                    bundle.add_resource_overriding(parse(&source, stuff, &mut errors));
+                }
+
+                bundle.set_use_isolating(false);
+                if pseudo {
+                    bundle.set_transform(Some(self::pseudo));
+                }
+
+                Some(bundle)
+            })
+            .collect();
+
+        Self {
+            bundles,
+            errors,
+            pseudo,
+        }
+    }
+}
 
-struct Locale(FluentBundle<FluentResource>);
+/// The translations of a single locale by where they come from, the ones laid over the others last
+pub fn sources(locale: &str) -> Vec<(String, String)> {
+    let embedded = EMBEDDED
+        .iter()
+        .find(|(id, _)| *id == locale)
+        .map(|(_, stuff)| (format!("{locale} (embedded)"), stuff.to_string()));
+    let path = format!("{LANG_DIR}/{locale}.ftl");
+    let file = std::fs::read_to_string(&path).ok().map(|stuff| (path, stuff));
 
-impl Locale {
-    pub fn new() -> Self {
-        let stuff = std::fs::read_to_string("lang/en-US.ftl").unwrap();
+    embedded.into_iter().chain(file).collect()
+}
 
-        let res = FluentResource::try_new(stuff).expect("Failed to parse an FTL string.");
+/// Parses a translation file, keeping every entry that could be read and noting the ones that couldn't
+pub fn parse(source: &str, stuff: String, errors: &mut Vec<String>) -> FluentResource {
+    FluentResource::try_new(stuff).unwrap_or_else(|(res, parse_errors)| {
+        for error in parse_errors {
+            let line = res.source()[..error.pos.start].matches('\n').count() + 1;
+            errors.push(format!("{source}:{line}: {error}"));
+        }
+        res
+    })
+}
 
-        let langid_en = langid!("en-US");
-        let mut bundle = FluentBundle::new(vec![langid_en]);
+// Safety: This is ok because only the render thread (the main thread) will be using i18n
+unsafe impl Sync for Locale {}
+unsafe impl Send for Locale {}
 
-        bundle
-            .add_resource(res)
-            .expect("Failed to add FTL resources to the bundle.");
+/// The locales a key is looked up in, `de-DE` goes through `de-DE`, `de` and then the default locale
+fn chain(locale: &str) -> Vec<LanguageIdentifier> {
+    let default: LanguageIdentifier = DEFAULT_LOCALE.parse().unwrap();
+    let mut chain = Vec::new();
+    if let Ok(langid) = locale.parse::<LanguageIdentifier>() {
+        let language = LanguageIdentifier::from_parts(langid.language, None, None, &[]);
+        chain.push(langid);
+        chain.push(language);
+    }
+    chain.push(default);
+    chain.dedup();
+    chain
+}
+
+/// Switches every string looked up from now on to `locale`
+pub fn set_locale(locale: &str) {
+    *LANG.write().unwrap() = Locale::new(locale);
+}
 
-        bundle.set_use_isolating(false);
+/// Problems found in the translation files of the current locale
+pub fn errors() -> Vec<String> {
+    LANG.read().unwrap().errors.clone()
+}
 
-        Self(bundle)
+/// Accents the text and pads it by about 40%, which is roughly how much longer translations run than
+/// english. Only accents from latin-1 are used so the built in font can still draw it
+fn pseudo(text: &str) -> Cow<str> {
+    const PLAIN: &str = "aAcCdDeEiInNoOpPuUyY";
+    const ACCENTED: &str = "áÁçÇðÐéÉíÍñÑóÓþÞúÚýÝ";
+
+    let mut pseudo = String::with_capacity(text.len() * 2);
+    let mut letters = 0;
+    for c in text.chars() {
+        if c.is_alphabetic() {
+            letters += 1;
+        }
+        pseudo.push(PLAIN.find(c).and_then(|idx| ACCENTED.chars().nth(idx)).unwrap_or(c));
     }
+    pseudo.extend(std::iter::repeat('~').take((letters * 2 + 4) / 5));
+
+    Cow::Owned(pseudo)
 }
 
-// Safety: This is ok because only the render thread (the main thread) will be using i18n
-unsafe impl Sync for Locale {}
-unsafe impl Send for Locale {}
+/// Every locale there is a translation for, built in or in [`LANG_DIR`], and the pseudo locale
+pub fn locales() -> Vec<String> {
+    let mut locales: Vec<String> = std::fs::read_dir(LANG_DIR)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "ftl" {
+                return None;
+            }
+            Some(path.file_stem()?.to_str()?.to_string())
+        })
+        .chain(EMBEDDED.iter().map(|(id, _)| id.to_string()))
+        .chain([PSEUDO_LOCALE.to_string()])
+        .collect();
+    locales.sort();
+    locales.dedup();
+    locales
+}
 
 pub fn loc<'a>(key: &'a str, args: Option<&'a FluentArgs>) -> Cow<'a, str> {
-    if let Some(msg) = LANG.0.get_message(key) {
-        if let Some(pattern) = msg.value() {
-            return LANG.0.format_pattern(pattern, args, &mut vec![]);
+    let lang = LANG.read().unwrap();
+    for bundle in &lang.bundles {
+        if let Some(msg) = bundle.get_message(key) {
+            if let Some(pattern) = msg.value() {
+                let text = bundle.format_pattern(pattern, args, &mut vec![]);
+                if lang.pseudo {
+                    return Cow::Owned(format!("[{text}]"));
+                }
+                return Cow::Owned(text.into_owned());
+            }
         }
     }
 
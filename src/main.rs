@@ -3,31 +3,81 @@
 #![allow(incomplete_features)]
 #![warn(clippy::all)]
 
+use crate::achievement::ACHIEVEMENTS;
 use crate::action::*;
+use crate::calendar::Calendar;
+use crate::challenge::{Challenge, Rules};
+use crate::city::City;
+use crate::civic::{Civic, Government, Job};
+use crate::clockwork::Clockwork;
+use crate::craft::RECIPES;
 use crate::engine::Engine;
+use crate::events::Events;
 use crate::evolution::Evolution;
+use crate::fonts::Glyphs;
+use crate::market::{Market, GOODS};
+use crate::message::{Arg, MessageKind, MessageLog};
+use crate::planet::Planet;
+use crate::power::{Grid, GRID};
+use crate::profile::Profile;
 use crate::race::{Race, Species};
 use crate::resource::{ResourceType, Resources};
+use crate::settings::{Notation, Settings};
+use crate::stats::Stats;
+use crate::storage::Storage;
+use crate::tech::{Tech, TECH_ELECTRICITY, TECH_FISSION, TECH_IRRIGATION};
+use crate::theme::Theme;
+use crate::universe::Universe;
 use fastrand::Rng;
-use imgui::{sys::ImGuiCol_Text, ImColor32, ItemHoveredFlags, ProgressBar, TableFlags, Ui};
+use imgui::{sys::ImGuiCol_Text, Condition, ItemHoveredFlags, ProgressBar, TableFlags, Ui};
 use once_cell::sync::Lazy;
 use resource::Cost;
 use serde::{Deserialize, Serialize};
-use std::{fs::File, sync::Mutex, time::Duration};
+use std::{borrow::Cow, fs::File, sync::Mutex, time::Duration};
 
+mod achievement;
 mod action;
+mod calendar;
+mod challenge;
+mod city;
+mod civic;
 mod clockwork;
+#[cfg(any(test, debug_assertions))]
+mod coverage;
+mod craft;
 mod engine;
+mod events;
 mod evolution;
+mod fonts;
 mod lang;
+mod market;
+mod message;
+mod planet;
+mod power;
+mod prestige;
+mod profile;
 mod race;
 mod resource;
+mod settings;
+mod stats;
+mod storage;
 mod structure;
+mod tech;
+mod theme;
+mod universe;
 mod util;
+#[cfg(debug_assertions)]
+mod watch;
 
 const VERSION: &'static str = concat!("v", env!("CARGO_PKG_VERSION"));
 
 fn main() {
+    // Debug command that checks the translations against the keys the code uses
+    #[cfg(debug_assertions)]
+    if std::env::args().any(|arg| arg == "--check-locales") {
+        std::process::exit(if coverage::check() { 0 } else { 1 });
+    }
+
     Engine::new("Evolve", [1024, 768]).run()
 }
 
@@ -37,53 +87,138 @@ pub struct Game {
     seed: u64,
     resources: Resources,
     evolution: Evolution, // TODO: dont serialize this once sentient
-    tech: (),
-    city: (),
-    civic: (),
+    tech: Tech,
+    city: City,
+    civic: Civic,
+    storage: Storage,
+    market: Market,
+    power: Grid,
+    calendar: Calendar,
+    events: Events,
+    log: MessageLog,
+    stats: Stats,
+    rules: Rules,
+    /// `None` until a universe is picked at the start of a run
+    universe: Option<Universe>,
+    /// `None` until one of the generated planets is picked at the start of evolution
+    planet: Option<Planet>,
     race: Race,
 
+    /// Kept in its own file, see [`Profile`]
+    #[serde(skip)]
+    profile: Profile,
+    /// Kept in its own file, see [`Settings`]
+    #[serde(skip)]
+    settings: Settings,
+    /// Loaded from the name in the settings, see [`Theme`]
+    #[serde(skip)]
+    theme: Theme,
+    /// Every theme that can be picked, read again when the theme list is opened
+    #[serde(skip)]
+    themes: Vec<Theme>,
+    /// Theme files that couldn't be read
+    #[serde(skip)]
+    theme_errors: Vec<String>,
     #[serde(skip)]
     rng: Rng,
+    /// Seconds since the last autosave
+    #[serde(skip)]
+    since_save: u32,
 
     // Ui stuff
     #[serde(skip)]
     actions: usize,
+    /// Action waiting for the player to confirm it
+    #[serde(skip)]
+    pending: Option<Action>,
+    /// Puts every panel back where the fixed layout has it on the next frame
+    #[serde(skip)]
+    reset_layout: bool,
+    /// Rebuilds the font atlas before the next frame
+    #[serde(skip)]
+    rebuild_fonts: bool,
+    /// Restyles imgui with the theme before the next frame
+    #[serde(skip)]
+    apply_theme: bool,
+    /// Picks up edits to the translations and themes while the game runs
+    #[cfg(debug_assertions)]
+    #[serde(skip)]
+    watcher: Option<watch::Watcher>,
 }
 
 static ACTIONS: Lazy<Mutex<ActionHolder>> = Lazy::new(|| Mutex::new(ActionHolder::new()));
 
 impl Game {
-    pub fn new(engine: &mut Engine) -> Self {
-        let Engine { clockwork, .. } = engine;
-
+    /// Registers the game loops, the clock doesn't care whether the game is new or loaded
+    pub fn schedule(clockwork: &mut Clockwork<Game>) {
         clockwork.every(Duration::from_millis(250)).run(Game::fast_loop);
         clockwork.every(Duration::from_millis(1000)).run(Game::mid_loop);
         clockwork.every(Duration::from_millis(5000)).run(Game::long_loop);
+    }
 
+    pub fn new() -> Self {
         ACTIONS.lock().unwrap().add(Category::Evolution, ACTION_RNA);
 
-        Self {
-            seed: 1,
+        let settings = Settings::load();
+        // Later runs are seeded from this one, so only the first needs a seed from outside the game
+        let seed = fastrand::u64(..);
+        let mut game = Self {
+            seed,
             resources: Resources::new(),
             evolution: Evolution::new(),
-            tech: (),
-            city: (),
-            civic: (),
+            tech: Tech::new(),
+            city: City::new(),
+            civic: Civic::new(),
+            storage: Storage::new(),
+            market: Market::new(),
+            power: Grid::new(),
+            calendar: Calendar::new(),
+            events: Events::new(),
+            log: MessageLog::new(),
+            stats: Stats::default(),
+            rules: Rules::default(),
+            universe: None,
+            planet: None,
             race: Race::default(),
 
-            rng: Rng::with_seed(1),
+            profile: Profile::load(),
+            theme: Theme::default(),
+            themes: Vec::new(),
+            theme_errors: Vec::new(),
+            settings,
+            rng: Rng::with_seed(seed),
+            since_save: 0,
             actions: 0,
-        }
+            pending: None,
+            reset_layout: false,
+            rebuild_fonts: false,
+            apply_theme: false,
+            #[cfg(debug_assertions)]
+            watcher: Some(watch::Watcher::new(&[lang::LANG_DIR, theme::THEMES_DIR])),
+        };
+        game.load_themes();
+        game
     }
 
-    #[allow(dead_code)]
-    fn load_save() -> Option<Self> {
+    pub fn load_save() -> Option<Self> {
         let content = match std::fs::read_to_string("save.json") {
             Ok(content) => content,
             Err(_) => return None,
         };
 
-        serde_json::from_str(&content).ok()
+        // The rng isn't saved, so reseed it to keep every save deterministic
+        serde_json::from_str(&content).ok().map(|mut game: Self| {
+            game.rng = Rng::with_seed(game.seed);
+            game.profile = Profile::load();
+            game.settings = Settings::load();
+            game.load_themes();
+            #[cfg(debug_assertions)]
+            {
+                game.watcher = Some(watch::Watcher::new(&[lang::LANG_DIR, theme::THEMES_DIR]));
+            }
+            game.restore_actions();
+            game
+        })
     }
 
     fn save(&self) {
@@ -95,11 +230,12 @@ impl Game {
 
     fn on_exit(&self) {
         self.save();
+        self.profile.save();
     }
 
     // Runs every 0.25 seconds
     fn fast_loop(&mut self) {
-        let global_mult = 1;
+        let global_mult = self.global_mult();
         let time_mult = 0.25;
         if matches!(self.race.species, Species::Protoplasm) {
             use ResourceType::*;
@@ -114,7 +250,7 @@ impl Game {
                 let rna = increment;
                 // TODO: bilateral_symmetry, poikilohydric, spores should upgrade this
 
-                self.mod_res(DNA, (increment * global_mult) as f32 * time_mult, false, false);
+                self.mod_res(DNA, increment as f32 * global_mult * time_mult, false, false);
                 self.mod_res(RNA, -((rna * 2) as f32 * time_mult), false, false);
             }
 
@@ -125,19 +261,24 @@ impl Game {
                     mult += 1;
                 }
 
-                self.mod_res(RNA, (organelles * mult * global_mult) as f32 * time_mult, false, false);
+                self.mod_res(RNA, (organelles * mult) as f32 * global_mult * time_mult, false, false);
             }
 
             // Detect new unlocks
             let Self {
-                resources, evolution, ..
+                resources,
+                evolution,
+                log,
+                ..
             } = self;
 
             let mut holder = ACTIONS.lock().unwrap();
+            let unlocked = holder[Category::Evolution].len();
 
             if resources.rna.amount >= 2.0 && !holder.unlocked(ACTION_DNA) {
 				holder.add(Category::Evolution, ACTION_DNA);
                 resources.dna.display = true;
+                evolution.dna_unlocked = true;
             } else if resources.rna.amount >= 10.0 && !holder.unlocked(ACTION_MEMBRANE) {
                 holder.add(Category::Evolution, ACTION_MEMBRANE);
 				evolution.membrane = Some(0);
@@ -157,8 +298,95 @@ impl Game {
 				holder.add(Category::Evolution, ACTION_SEXUAL_REPRODUCTION);
 				evolution.sexual_reproduction = Some(false);
             }
+
+            if holder[Category::Evolution].len() > unlocked
+                && let Some(action) = holder[Category::Evolution].last()
+            {
+                let title = Arg::Key(action.title.to_string());
+                log.push(MessageKind::Info, msg!("log_evolution_unlocked", title));
+            }
+        } else {
+            use ResourceType::*;
+            if self.tech.researched(TECH_ELECTRICITY.id) {
+                let brownout = self.power.brownout();
+                power::balance(self, time_mult);
+                if !brownout && self.power.brownout() {
+                    self.log.push(MessageKind::Warning, msg!("log_brownout"));
+                }
+            }
+
+            for job in Job::iter() {
+                let workers = self.civic.jobs[job].unwrap_or(0);
+                if workers == 0 {
+                    continue;
+                }
+
+                let (res, base) = job.output();
+                let mut staff = workers as f32;
+                if matches!(job, Job::Miner) {
+                    staff += power::powered(self, ACTION_MINE.id, workers) as f32 * power::POWERED_BOOST;
+                }
+
+                let mut mult = 1.0;
+                if matches!(job, Job::Farmer) && self.tech.researched(TECH_IRRIGATION.id) {
+                    mult += 0.4;
+                }
+                if matches!(job, Job::Professor) {
+                    mult *= self.civic.government.knowledge();
+                }
+                mult *= self.calendar.job_mult(job);
+                if let Some(planet) = &self.planet {
+                    mult *= planet.biome.job_mult(job);
+                }
+
+                let amount = staff * base * mult * global_mult;
+                self.mod_res(res, amount * time_mult, false, false);
+            }
+
+            // Oil wells pump on their own, and coal miners dig up uranium once fission is known
+            if let Some(wells) = self.city.oil_well {
+                self.mod_res(Oil, wells as f32 * 0.4 * global_mult * time_mult, false, false);
+            }
+            if self.tech.researched(TECH_FISSION.id) {
+                let miners = self.civic.jobs.coal_miner.unwrap_or(0);
+                self.mod_res(Uranium, miners as f32 * 0.01 * global_mult * time_mult, false, false);
+            }
+
+            // Collect taxes
+            if let Some(rate) = self.civic.tax_rate {
+                let income = self.civic.citizens as f32 * rate as f32 / 200.0 * self.civic.government.taxes();
+                self.mod_res(Money, income * global_mult * time_mult, false, false);
+            }
+
+            // Citizens eat, and start starving once there is no food left
+            let eaten = self.civic.citizens as f32 * 0.25;
+            if !self.mod_res(Food, -eaten * time_mult, false, false) {
+                if self.civic.citizens > 0 && !self.civic.starving {
+                    self.log.push(MessageKind::Warning, msg!("log_starved"));
+                }
+                self.civic.starving = true;
+            } else {
+                self.civic.starving = false;
+            }
+
+            // Stockpiles rot away under the decay challenge
+            let decay = self.rules.decay();
+            if decay > 0.0 {
+                for res in ResourceType::iter().filter(|&res| storage::crateable(res)) {
+                    let lost = self.resources[res].amount * decay;
+                    self.mod_res(res, -lost * time_mult, false, false);
+                }
+            }
+
+            craft::auto_craft(self, time_mult);
+            market::trade(self, time_mult);
+
+            // Detect new techs
+            tech::unlock(&self.tech, &mut ACTIONS.lock().unwrap(), &mut self.log);
         }
 
+        universe::channel(self, time_mult);
+
         // main resource tracking
         for res in ResourceType::iter() {
             let resource = &self.resources[res];
@@ -171,43 +399,87 @@ impl Game {
     // Runs every 1 second
     fn mid_loop(&mut self) {
         // update resource caps
-        if matches!(self.race.species, Species::Protoplasm) {
-            let base = 100.0;
-            let mut rna_cap = base;
-            let mut dna_cap = base;
-            if let Some(membrane) = self.evolution.membrane {
-                let effect = match self.evolution.mitochondria {
-                    Some(count) => count * 5 + 5,
-                    None => 5,
-                };
-                rna_cap += (membrane * effect) as f32;
+        for res in ResourceType::iter() {
+            self.resources[res].max = storage::cap(self, res);
+        }
+
+        stats::tick(self, 1);
+        achievement::check(self);
+
+        #[cfg(debug_assertions)]
+        self.hot_reload();
+
+        if !matches!(self.race.species, Species::Protoplasm) {
+            market::drift(self);
+
+            if let Some(cooldown) = self.civic.government_cooldown.as_mut() {
+                *cooldown = cooldown.saturating_sub(1);
             }
-            if let Some(eukaryotic_cell) = self.evolution.eukaryotic_cell {
-                let effect = match self.evolution.mitochondria {
-                    Some(count) => count * 10 + 10,
-                    None => 10,
-                };
-                dna_cap += (eukaryotic_cell * effect) as f32;
+            self.civic.update_morale(self.rules.max_morale());
+
+            // one citizen starves every second the food stays gone
+            if self.civic.starving {
+                self.civic.starve();
             }
 
-            self.resources.rna.max = rna_cap;
-            self.resources.dna.max = dna_cap;
-        } else {
+            // population growth
+            if self.civic.citizens < self.city.max_citizens()
+                && self.resources.food.amount > 0.0
+                && self.rng.u32(0..3) == 0
+            {
+                self.civic.citizens += 1;
+            }
         }
     }
 
     // Runs every 5 seconds
     fn long_loop(&mut self) {
-        // autosave
-        self.save();
+        if !matches!(self.race.species, Species::Protoplasm) {
+            self.calendar.advance(&self.rng);
+            events::roll(self);
+        }
+
+        // autosave, the long loop runs every 5 seconds
+        self.since_save += 5;
+        if self.since_save >= self.settings.autosave {
+            self.since_save = 0;
+            self.save();
+            self.profile.save();
+        }
     }
 
     pub fn update(&mut self, ui: &mut Ui) {
         ui.main_menu_bar(|| {
-            ui.text("Prehistoric");
+            if matches!(self.race.species, Species::Protoplasm) {
+                ui.text(loc!("ui_prehistoric"));
+            } else {
+                let Calendar { day, year, weather, .. } = self.calendar;
+                ui.text(loc!("ui_date", year, day));
+                ui.separator();
+                ui.text(self.calendar.season().title());
+                ui.separator();
+                ui.text(format!("{} {}", weather.icon(), weather.title()));
+            }
+            if let Some(universe) = self.universe {
+                ui.separator();
+                ui.text(universe.title());
+            }
+            if let Some(planet) = &self.planet {
+                ui.separator();
+                ui.text(format!("{} ({})", planet.name, planet.biome.title()));
+            }
+            if self.profile.plasmids > 0 {
+                ui.separator();
+                let plasmids = self.profile.plasmids;
+                ui.text(loc!("ui_plasmids", plasmids));
+                if ui.is_item_hovered() {
+                    let bonus = ((prestige::mult(self) - 1.0) * 100.0).round();
+                    ui.tooltip_text(loc!("ui_production_bonus", bonus));
+                }
+            }
             util::right_align(ui, VERSION);
         });
-        util::statusbar(|| ui.text("Evolve by John"));
+        util::statusbar(|| ui.text(loc!("ui_credits")));
 
         let (width, height, pos) = unsafe {
             let viewport = *imgui::sys::igGetMainViewport();
@@ -217,149 +489,1059 @@ impl Game {
             (size.x, size.y - offset, pos)
         };
 
-        ui.window("left panel")
-            .size([width / 4.0, height], imgui::Condition::Always)
-            .position([pos.x, pos.y], imgui::Condition::Always)
+        // The free layout starts panels out where the fixed layout has them, then leaves them to the player
+        let free = self.settings.free_layout;
+        let cond = if free && !self.reset_layout {
+            Condition::FirstUseEver
+        } else {
+            Condition::Always
+        };
+        if free {
+            util::dockspace();
+        }
+
+        if self.reset_layout {
+            util::undock();
+        }
+        ui.window(format!("{}###left panel", loc!("panel_resources")))
+            .size([width / 4.0, height], cond)
+            .position([pos.x, pos.y], cond)
             .focused(false)
-            .title_bar(false)
-            .movable(false)
-            .resizable(false)
-            .draw_background(false)
+            .title_bar(free)
+            .movable(free)
+            .resizable(free)
+            .draw_background(free)
             .build(|| {
                 let size = ui.content_region_avail();
                 if let Some(_) = ui.begin_table_with_sizing("res table", 3, TableFlags::ROW_BG, size, 0.0) {
+                    let notation = self.settings.notation;
                     ResourceType::iter().for_each(|res| {
                         let resource = &self.resources[res];
                         if resource.display {
                             ui.table_next_column();
-                            ui.text(format!("{res}"));
+                            if let Some(color) = self.theme.resource(res) {
+                                ui.text_colored(color, format!("{res}"));
+                            } else {
+                                ui.text(format!("{res}"));
+                            }
                             ui.table_next_column();
-                            util::right_align(ui, format!("{}/{}", resource.amount.floor(), resource.max));
+                            let amount = notation.format(resource.amount.floor());
+                            if resource.max == -1.0 {
+                                util::right_align(ui, amount);
+                            } else {
+                                util::right_align(ui, format!("{}/{}", amount, notation.format(resource.max)));
+                            }
                             ui.table_next_column();
-                            util::right_align(ui, format!("{} /s", resource.diff));
+                            util::right_align(ui, format!("{} /s", notation.format(resource.diff)));
                         }
                     });
                 }
             });
 
-        ui.window("main panel")
-            .size([width / 2.0, height], imgui::Condition::Always)
-            .position([width / 4.0, pos.y], imgui::Condition::Always)
+        if self.reset_layout {
+            util::undock();
+        }
+        ui.window(format!("{}###main panel", loc!("panel_main")))
+            .size([width / 2.0, height], cond)
+            .position([width / 4.0, pos.y], cond)
             .focused(false)
-            .title_bar(false)
-            .movable(false)
-            .resizable(false)
+            .title_bar(free)
+            .movable(free)
+            .resizable(free)
             // .draw_background(false)
             .build(|| {
+                if self.tech.researched(TECH_ELECTRICITY.id) {
+                    // Passed as text, fluent would print the f32 widened to an f64 with all its noise
+                    let (supply, demand) = (self.power.supply.to_string(), self.power.demand.to_string());
+                    if self.power.brownout() {
+                        ui.text_colored([1.0, 0.4, 0.4, 1.0], loc!("ui_power_brownout", supply, demand));
+                    } else {
+                        ui.text(loc!("ui_power", supply, demand));
+                    }
+                }
+
                 if let Some(_tab) = ui.tab_bar("tabs") {
                     if self.race.species == Species::Protoplasm {
-                        if let Some(_tab) = ui.tab_item("Evolve") {
-                            let actions = ACTIONS.lock().unwrap()[Category::Evolution].clone();
-
-                            let style = unsafe { ui.style() };
-                            let mut width = ui.window_size()[0];
-                            width -= 2.0 * style.window_padding[0];
-                            width -= 6.0 * style.frame_padding[0];
-                            width /= 4.0;
-                            let size = [width, 48.0];
-
-                            for (idx, action) in actions.into_iter().enumerate() {
-                                let mut p1 = ui.cursor_screen_pos();
-                                let costs = action.cost(self);
-
-                                ui.enabled(self.afford(&costs), || {
-                                    if ui.button_with_size(action.title(), size) {
-                                        action.execute(self)
-                                    }
-                                });
-                                if ui.is_item_hovered_with_flags(ItemHoveredFlags::ALLOW_WHEN_DISABLED) {
-                                    ui.tooltip(|| {
-                                        ui.text(action.description());
-                                        for (idx, cost) in costs.iter().enumerate() {
-                                            if idx == 0 {
-                                                ui.separator();
-                                            }
-                                            ui.text(format!("{}: {}", cost.resource, cost.amount))
-                                        }
-                                        if let Some(text) = action.effect(self) {
-                                            ui.separator();
-                                            ui.text(text);
-                                        }
-                                    });
-                                }
+                        if let Some(_tab) = ui.tab_item(format!("{}###evolve", loc!("tab_evolve"))) {
+                            if self.universe.is_none() {
+                                self.universes(ui);
+                            } else if self.planet.is_none() {
+                                self.planets(ui);
+                            } else {
+                                self.action_grid(ui, Category::Evolution);
 
-                                if let Some(count) = action.count(self) {
-                                    if count != 0 {
-                                        let text = format!("{count}");
-                                        let text_size = ui.calc_text_size(&text);
-                                        p1[0] += width - text_size[0] - 7.0;
-                                        let p2 = [p1[0] + text_size[0] + 7.0, p1[1] + text_size[1] + 1.0];
-
-                                        let draw = ui.get_window_draw_list();
-
-                                        draw.add_rect(p1, p2, ImColor32::from_rgb(40, 40, 40))
-                                            .filled(true)
-                                            .rounding(5.0)
-                                            .round_bot_right(false)
-                                            .round_top_left(false)
-                                            .round_top_right(false)
-                                            .build();
-
-                                        draw.add_text([p1[0] + 4.0, p1[1]], style.colors[ImGuiCol_Text as usize], text);
-                                    }
-                                }
-
-                                if (idx + 1) % 4 != 0 {
-                                    ui.same_line();
+                                if let Some(progress) = self.evolution.progress {
+                                    ui.new_line();
+                                    ui.spacing();
+                                    ProgressBar::new(progress as f32 / 100.0)
+                                        .overlay_text(loc!("ui_evolving"))
+                                        .build(ui);
                                 }
                             }
-
-                            if let Some(progress) = self.evolution.progress {
-                                ui.new_line();
-                                ui.spacing();
-                                ProgressBar::new(progress as f32 / 100.0)
-                                    .overlay_text("Evolving")
-                                    .build(ui);
+                        }
+                    } else {
+                        if let Some(_tab) = ui.tab_item(format!("{}###city", loc!("tab_city"))) {
+                            self.action_grid(ui, Category::City);
+                            ui.new_line();
+                            ui.separator();
+                            self.jobs(ui);
+                            if self.tech.researched(TECH_ELECTRICITY.id) {
+                                ui.separator();
+                                self.power_grid(ui);
+                            }
+                        }
+                        if let Some(_tab) = ui.tab_item(format!("{}###research", loc!("tab_research"))) {
+                            self.action_grid(ui, Category::Research);
+                            ui.new_line();
+                            ui.separator();
+                            ui.text(loc!("ui_completed"));
+                            for research in self.tech.completed() {
+                                ui.bullet_text(research.action.title());
+                            }
+                        }
+                        if let Some(_tab) = ui.tab_item(format!("{}###civics", loc!("tab_civics"))) {
+                            self.civics(ui);
+                        }
+                        if self.city.shed.is_some() {
+                            if let Some(_tab) = ui.tab_item(format!("{}###storage", loc!("tab_storage"))) {
+                                self.storage(ui);
+                            }
+                        }
+                        if self.city.trade_post.is_some() && self.rules.trade() {
+                            if let Some(_tab) = ui.tab_item(format!("{}###market", loc!("tab_market"))) {
+                                self.market(ui);
+                            }
+                        }
+                        if RECIPES.iter().any(|recipe| recipe.available(self)) {
+                            if let Some(_tab) = ui.tab_item(format!("{}###crafting", loc!("tab_crafting"))) {
+                                self.crafting(ui);
                             }
                         }
                     }
-                    if let Some(_tab) = ui.tab_item("Settings") {}
+                    if !free {
+                        if let Some(_tab) = ui.tab_item(format!("{}###stats", loc!("tab_stats"))) {
+                            self.stats(ui);
+                        }
+                    }
+                    if let Some(_tab) = ui.tab_item(format!("{}###achievements", loc!("tab_achievements"))) {
+                        self.achievements(ui);
+                    }
+                    if let Some(_tab) = ui.tab_item(format!("{}###settings", loc!("tab_settings"))) {
+                        self.preferences(ui);
+                    }
                 }
+
+                self.confirm(ui);
             });
 
-        ui.window("right panel")
-            .size([width / 4.0, height], imgui::Condition::Always)
-            .position([3.0 * width / 4.0, pos.y], imgui::Condition::Always)
+        if free {
+            if self.reset_layout {
+                util::undock();
+            }
+            ui.window(format!("{}###stats panel", loc!("panel_stats")))
+                .size([width / 2.0, height / 2.0], cond)
+                .position([width / 4.0, pos.y + height / 2.0], cond)
+                .build(|| self.stats(ui));
+        }
+
+        if self.reset_layout {
+            util::undock();
+        }
+        ui.window(format!("{}###right panel", loc!("panel_messages")))
+            .size([width / 4.0, height], cond)
+            .position([3.0 * width / 4.0, pos.y], cond)
             .focused(false)
-            .title_bar(false)
-            .movable(false)
-            .resizable(false)
-            .draw_background(false)
+            .title_bar(free)
+            .movable(free)
+            .resizable(free)
+            .draw_background(free)
             .build(|| {
+                self.messages(ui);
+
                 #[cfg(debug_assertions)]
                 {
-                    ui.text("Cheats");
-                    if ui.button("Fill resources") {
+                    ui.separator();
+                    ui.text(loc!("ui_cheats"));
+                    if ui.button(loc!("ui_cheat_fill")) {
                         ResourceType::iter().for_each(|res| {
                             let res = &mut self.resources[res];
-                            res.amount = res.max;
+                            if res.max != -1.0 {
+                                res.amount = res.max;
+                            }
                         });
                     }
 
-                    if ui.button("Reset Save") {
-                        self.resources = Resources::new();
-                        self.evolution = Evolution::new();
-                        ACTIONS.lock().unwrap().clear();
+                    if ui.button(loc!("ui_cheat_reset")) {
+                        self.request(ACTION_RESET_SAVE);
+                    }
+                }
+            });
+
+        self.reset_layout = false;
+    }
+
+    fn action_grid(&mut self, ui: &Ui, category: Category) {
+        let actions = ACTIONS.lock().unwrap()[category].clone();
+
+        let style = unsafe { ui.style() };
+        let mut width = ui.window_size()[0];
+        width -= 2.0 * style.window_padding[0];
+        width -= 6.0 * style.frame_padding[0];
+        width /= 4.0;
+        let size = [width, 48.0];
+
+        for (idx, action) in actions.into_iter().enumerate() {
+            let mut p1 = ui.cursor_screen_pos();
+            let costs = action.cost(self);
+
+            ui.enabled(self.afford(&costs), || {
+                if ui.button_with_size(action.title(), size) {
+                    if action.id == prestige::ACTION_MAD.id {
+                        self.request(action.clone());
+                    } else {
+                        action.execute(self)
+                    }
+                }
+            });
+            if ui.is_item_hovered_with_flags(ItemHoveredFlags::ALLOW_WHEN_DISABLED) {
+                ui.tooltip(|| {
+                    ui.text(action.description());
+                    for (idx, cost) in costs.iter().enumerate() {
+                        if idx == 0 {
+                            ui.separator();
+                        }
+                        ui.text(format!("{}: {}", cost.resource, cost.amount))
+                    }
+                    if let Some(text) = action.effect(self) {
+                        ui.separator();
+                        ui.text(text);
+                    }
+                    if let Some(structure) = power::find(action.id)
+                        && self.tech.researched(TECH_ELECTRICITY.id)
+                    {
+                        ui.separator();
+                        let power = structure.power.to_string();
+                        if let Some(fuel) = structure.fuel {
+                            let (amount, resource) = (fuel.amount.to_string(), fuel.resource.to_string());
+                            ui.text(loc!("ui_power_plant", power, amount, resource));
+                        } else {
+                            ui.text(loc!("ui_power_consumer", power));
+                        }
+                        let running = self.power.running(action.id);
+                        let enabled = power::enabled(self, structure);
+                        let off = self.power.off(action.id);
+                        ui.text(loc!("ui_power_running", running, enabled, off));
+                    }
+                });
+            }
+
+            if let Some(count) = action.count(self) {
+                if count != 0 {
+                    let text = format!("{count}");
+                    let text_size = ui.calc_text_size(&text);
+                    p1[0] += width - text_size[0] - 7.0;
+                    let p2 = [p1[0] + text_size[0] + 7.0, p1[1] + text_size[1] + 1.0];
+
+                    let draw = ui.get_window_draw_list();
+
+                    draw.add_rect(p1, p2, self.theme.badge)
+                        .filled(true)
+                        .rounding(5.0)
+                        .round_bot_right(false)
+                        .round_top_left(false)
+                        .round_top_right(false)
+                        .build();
+
+                    draw.add_text([p1[0] + 4.0, p1[1]], style.colors[ImGuiCol_Text as usize], text);
+                }
+            }
+
+            if (idx + 1) % 4 != 0 {
+                ui.same_line();
+            }
+        }
+    }
+
+    /// Runs an action that can't be undone, leaving it pending until confirmed if the settings ask for that
+    fn request(&mut self, action: Action) {
+        if self.settings.confirm {
+            self.pending = Some(action);
+        } else {
+            action.execute(self);
+        }
+    }
+
+    /// Asks before running the pending action, if there is one
+    fn confirm(&mut self, ui: &Ui) {
+        let title = format!("{}###confirm", loc!("ui_confirm"));
+        if self.pending.is_some() {
+            ui.open_popup(&title);
+        }
+
+        ui.modal_popup(&title, || {
+            if let Some(action) = self.pending.clone() {
+                ui.text(action.description());
+                if let Some(text) = action.effect(self) {
+                    ui.text(text);
+                }
+            }
+
+            if ui.button(loc!("ui_yes")) {
+                if let Some(action) = self.pending.take() {
+                    action.execute(self);
+                }
+                ui.close_current_popup();
+            }
+            ui.same_line();
+            if ui.button(loc!("ui_no")) {
+                self.pending = None;
+                ui.close_current_popup();
+            }
+        });
+    }
+
+    fn fonts(&mut self, ui: &Ui) {
+        let settings = &mut self.settings;
+        let mut changed = false;
+
+        ui.slider(loc!("settings_font_size"), 8.0, 32.0, &mut settings.font_size);
+        // Rebuilding the atlas is slow, so wait until the slider is let go
+        changed |= ui.is_item_deactivated_after_edit();
+
+        let fonts = fonts::available();
+        for (label, font) in [
+            (loc!("settings_font"), &mut settings.font),
+            (loc!("settings_fallback_font"), &mut settings.fallback_font),
+        ] {
+            let builtin = loc!("settings_builtin_font");
+            if let Some(_combo) = ui.begin_combo(label, font.as_deref().unwrap_or(&builtin)) {
+                if ui.selectable_config(&builtin).selected(font.is_none()).build() {
+                    *font = None;
+                    changed = true;
+                }
+                for name in &fonts {
+                    if ui.selectable_config(name).selected(font.as_ref() == Some(name)).build() {
+                        *font = Some(name.clone());
+                        changed = true;
+                    }
+                }
+            }
+        }
+        if ui.is_item_hovered() {
+            ui.tooltip_text(loc!("settings_fallback_font_tooltip"));
+        }
+
+        if let Some(_combo) = ui.begin_combo(loc!("settings_glyphs"), settings.glyphs.title()) {
+            for glyphs in Glyphs::iter() {
+                if ui
+                    .selectable_config(glyphs.title())
+                    .selected(glyphs == settings.glyphs)
+                    .build()
+                {
+                    settings.glyphs = glyphs;
+                    changed = true;
+                }
+            }
+        }
+        let dir = fonts::FONTS_DIR;
+        ui.text_disabled(loc!("settings_fonts_dir", dir));
+
+        if changed {
+            settings.save();
+            self.rebuild_fonts = true;
+        }
+    }
+
+    fn preferences(&mut self, ui: &Ui) {
+        let settings = &mut self.settings;
+        let mut changed = false;
+
+        if let Some(_combo) = ui.begin_combo(loc!("settings_notation"), settings.notation.title()) {
+            for notation in Notation::iter() {
+                if ui
+                    .selectable_config(notation.title())
+                    .selected(notation == settings.notation)
+                    .build()
+                {
+                    settings.notation = notation;
+                    changed = true;
+                }
+            }
+        }
+        let example = settings.notation.format(1234567.0);
+        ui.text_disabled(loc!("settings_notation_example", example));
+
+        changed |= ui.slider(loc!("settings_autosave"), 5, 300, &mut settings.autosave);
+        changed |= ui.slider(loc!("settings_ui_scale"), 0.5, 2.0, &mut settings.ui_scale);
+
+        if let Some(_combo) = ui.begin_combo(loc!("settings_theme"), self.theme.title()) {
+            // Picks up themes added since the list was last opened
+            if ui.is_window_appearing() {
+                self.theme_errors.clear();
+                self.themes = theme::themes(&mut self.theme_errors);
+            }
+            for theme in &self.themes {
+                if ui
+                    .selectable_config(theme.title())
+                    .selected(theme.name == settings.theme)
+                    .build()
+                {
+                    settings.theme = theme.name.clone();
+                    self.theme = theme.clone();
+                    self.apply_theme = true;
+                    changed = true;
+                }
+            }
+        }
+        if ui.is_item_hovered() {
+            let dir = theme::THEMES_DIR;
+            ui.tooltip_text(loc!("settings_themes_dir", dir));
+        }
+        for error in &self.theme_errors {
+            ui.text_colored(MessageKind::Warning.color(), error);
+        }
+
+        if let Some(_combo) = ui.begin_combo(loc!("settings_language"), &settings.locale) {
+            for locale in lang::locales() {
+                if ui
+                    .selectable_config(&locale)
+                    .selected(locale == settings.locale)
+                    .build()
+                {
+                    lang::set_locale(&locale);
+                    settings.locale = locale;
+                    changed = true;
+                }
+            }
+        }
+        for error in lang::errors() {
+            ui.text_colored(MessageKind::Warning.color(), error);
+        }
+
+        changed |= ui.checkbox(loc!("settings_confirm"), &mut settings.confirm);
+        changed |= ui.checkbox(loc!("settings_free_layout"), &mut settings.free_layout);
+        if ui.is_item_hovered() {
+            ui.tooltip_text(loc!("settings_free_layout_tooltip"));
+        }
+        ui.same_line();
+        if ui.button(loc!("settings_reset_layout")) {
+            let _ = std::fs::remove_file(settings::LAYOUT_FILE);
+            self.reset_layout = true;
+        }
+
+        if changed {
+            settings.save();
+        }
+
+        ui.separator();
+        self.fonts(ui);
+    }
+
+    fn civics(&mut self, ui: &Ui) {
+        let civic = &mut self.civic;
+
+        let government = civic.government.title();
+        ui.text(loc!("ui_government", government));
+        if ui.is_item_hovered() {
+            ui.tooltip_text(civic.government.description());
+        }
+
+        if let Some(cooldown) = civic.government_cooldown {
+            if cooldown > 0 {
+                ui.text(loc!("ui_revolution", cooldown));
+            }
+
+            for government in Government::iter() {
+                if government == civic.government {
+                    continue;
+                }
+
+                ui.enabled(cooldown == 0, || {
+                    if ui.button(government.title()) {
+                        civic.set_government(government);
+                    }
+                });
+                if ui.is_item_hovered_with_flags(ItemHoveredFlags::ALLOW_WHEN_DISABLED) {
+                    ui.tooltip_text(government.description());
+                }
+                ui.same_line();
+            }
+            ui.new_line();
+        }
+
+        ui.separator();
+        if let Some(rate) = civic.tax_rate.as_mut() {
+            ui.slider(loc!("ui_tax_rate"), 0, 50, rate);
+        }
+        let morale = civic.morale.to_string();
+        ui.text(loc!("ui_morale", morale));
+    }
+
+    fn market(&mut self, ui: &Ui) {
+        if let Some(_table) = ui.begin_table("market", 4) {
+            for &(res, _) in GOODS {
+                if !self.resources[res].display {
+                    continue;
+                }
+
+                ui.table_next_column();
+                ui.text(format!("{res}"));
+                ui.table_next_column();
+                ui.text(format!(
+                    "{:.2} / {:.2}",
+                    self.market.price(res),
+                    self.market.sell_price(res)
+                ));
+
+                ui.table_next_column();
+                for amount in [10.0, 100.0] {
+                    let label: Cow<str> = loc!("ui_buy", amount);
+                    if ui.small_button(format!("{label}##{res:?}")) {
+                        market::buy(self, res, amount);
+                    }
+                    ui.same_line();
+                }
+
+                ui.table_next_column();
+                for amount in [10.0, 100.0] {
+                    let label: Cow<str> = loc!("ui_sell", amount);
+                    if ui.small_button(format!("{label}##{res:?}")) {
+                        market::sell(self, res, amount);
                     }
+                    ui.same_line();
                 }
+            }
+        }
+
+        ui.separator();
+        let slots = self.city.trade_post.unwrap_or(0) as usize;
+        let active = self.market.active_routes();
+        ui.text(loc!("ui_trade_routes", active, slots));
+
+        for (idx, route) in self.market.routes.iter_mut().enumerate() {
+            let resource = route.res.to_string();
+            let text = if route.amount > 0.0 {
+                let amount = route.amount.to_string();
+                let label: Cow<str> = loc!("ui_route_buy", amount, resource);
+                format!("{label}##route{idx}")
+            } else {
+                let amount = (-route.amount).to_string();
+                let label: Cow<str> = loc!("ui_route_sell", amount, resource);
+                format!("{label}##route{idx}")
+            };
+
+            ui.enabled(route.active || active < slots, || {
+                ui.checkbox(text, &mut route.active);
             });
+        }
+    }
+
+    fn storage(&mut self, ui: &Ui) {
+        use ResourceType::*;
+
+        let containers = self.city.warehouse.is_some();
+        let columns = if containers { 4 } else { 3 };
+
+        if let Some(_table) = ui.begin_table("storage", columns) {
+            for res in ResourceType::iter() {
+                if !storage::crateable(res) || !self.resources[res].display {
+                    continue;
+                }
+
+                ui.table_next_column();
+                ui.text(format!("{res}"));
+                ui.table_next_column();
+                ui.text(format!("{}", self.resources[res].max));
+
+                let kinds: &[ResourceType] = if containers { &[Crates, Containers] } else { &[Crates] };
+                for &kind in kinds {
+                    ui.table_next_column();
+                    if ui.small_button(format!("-##{kind:?}{res:?}")) {
+                        storage::unassign(self, kind, res);
+                    }
+                    ui.same_line();
+                    ui.text(format!("{} {kind}", self.storage.assigned(kind, res)));
+                    ui.same_line();
+                    if ui.small_button(format!("+##{kind:?}{res:?}")) {
+                        storage::assign(self, kind, res);
+                    }
+                }
+            }
+        }
+    }
+
+    fn crafting(&mut self, ui: &Ui) {
+        let foundries = self.city.foundry.unwrap_or(0);
+        let craftsmen = self.civic.craftsmen();
+        ui.text(loc!("ui_craftsmen", craftsmen, foundries));
+
+        if let Some(_table) = ui.begin_table("crafting", 3) {
+            for recipe in RECIPES {
+                if !recipe.available(self) {
+                    continue;
+                }
+                let res = recipe.output;
+
+                ui.table_next_column();
+                ui.text(format!("{res}"));
+                if ui.is_item_hovered() {
+                    ui.tooltip(|| {
+                        for input in recipe.inputs {
+                            ui.text(format!("{}: {}", input.resource, input.amount))
+                        }
+                    });
+                }
+
+                ui.table_next_column();
+                let affordable = recipe.affordable(self);
+                let all = loc!("ui_craft_all");
+                for (label, times) in [("x1", Some(1)), ("x5", Some(5)), ("x25", Some(25)), (&*all, None)] {
+                    ui.enabled(affordable >= times.unwrap_or(1), || {
+                        if ui.small_button(format!("{label}##{res:?}")) {
+                            craft::craft(self, recipe, times);
+                        }
+                    });
+                    ui.same_line();
+                }
+
+                ui.table_next_column();
+                if foundries > 0 {
+                    let count = self.civic.crafters.get(&res).copied().unwrap_or(0);
+                    if ui.small_button(format!("-##crafter{res:?}")) {
+                        self.civic.unassign_crafter(res);
+                    }
+                    ui.same_line();
+                    ui.text(format!("{count}"));
+                    ui.same_line();
+                    if ui.small_button(format!("+##crafter{res:?}")) {
+                        self.civic.assign_crafter(res, foundries);
+                    }
+                }
+            }
+        }
+    }
+
+    fn power_grid(&mut self, ui: &Ui) {
+        for structure in GRID {
+            let built = (structure.count)(&self.city).unwrap_or(0);
+            if built == 0 {
+                continue;
+            }
+            let id = structure.action.id;
+
+            let title = structure.action.title();
+            let running = self.power.running(id);
+            ui.text(loc!("ui_grid_running", title, running, built));
+            ui.same_line();
+            if ui.small_button(format!("{}##{id}", loc!("ui_switch_off"))) {
+                self.power.switch_off(id, built);
+            }
+            ui.same_line();
+            if ui.small_button(format!("{}##{id}", loc!("ui_switch_on"))) {
+                self.power.switch_on(id);
+            }
+        }
+    }
+
+    fn universes(&mut self, ui: &Ui) {
+        ui.text(loc!("ui_choose_universe"));
+        ui.separator();
+
+        for universe in Universe::iter() {
+            if ui.button(universe.title()) {
+                self.universe = Some(universe);
+                self.resources.mana.display = universe == Universe::Magic;
+            }
+            if ui.is_item_hovered() {
+                ui.tooltip_text(universe.description());
+            }
+        }
+    }
+
+    fn planets(&mut self, ui: &Ui) {
+        ui.text(loc!("ui_choose_challenges"));
+        for challenge in Challenge::iter() {
+            let mut active = self.rules.active(challenge);
+            if ui.checkbox(challenge.title(), &mut active) {
+                self.rules.toggle(challenge);
+            }
+            if ui.is_item_hovered() {
+                ui.tooltip_text(challenge.description());
+            }
+        }
+
+        ui.separator();
+        ui.text(loc!("ui_choose_planet"));
+        ui.separator();
+
+        for (idx, planet) in planet::choices(self.seed).into_iter().enumerate() {
+            let _id = ui.push_id_usize(idx);
+            if ui.button(&planet.name) {
+                self.calendar.orbit = planet.orbit;
+                self.planet = Some(planet.clone());
+            }
+            ui.same_line();
+            let (biome, orbit) = (planet.biome.title(), planet.orbit);
+            ui.text(loc!("ui_planet", biome, orbit));
+            if ui.is_item_hovered() {
+                ui.tooltip_text(planet.biome.description());
+            }
+        }
+    }
+
+    fn stats(&mut self, ui: &Ui) {
+        let (run, lifetime) = (&self.stats, &self.profile.stats);
+
+        if let Some(_table) = ui.begin_table("stats", 3) {
+            ui.table_next_column();
+            ui.table_next_column();
+            ui.text(loc!("stats_run"));
+            ui.table_next_column();
+            ui.text(loc!("stats_lifetime"));
+
+            let rows = [
+                (
+                    loc!("stats_time"),
+                    stats::duration(run.time),
+                    stats::duration(lifetime.time),
+                ),
+                (
+                    loc!("stats_evolution_time"),
+                    run.evolution_time.map_or("-".to_string(), stats::duration),
+                    lifetime.evolution_time.map_or("-".to_string(), stats::duration),
+                ),
+                (loc!("stats_resets"), "-".to_string(), self.profile.resets.to_string()),
+                (
+                    loc!("stats_species"),
+                    run.species.len().to_string(),
+                    lifetime.species.len().to_string(),
+                ),
+            ];
+            for (label, run, lifetime) in rows {
+                ui.table_next_column();
+                ui.text(label);
+                ui.table_next_column();
+                ui.text(run);
+                ui.table_next_column();
+                ui.text(lifetime);
+            }
+            for challenge in Challenge::iter() {
+                ui.table_next_column();
+                ui.text(challenge.title());
+                ui.table_next_column();
+                ui.text(if self.rules.active(challenge) {
+                    loc!("stats_active")
+                } else {
+                    Cow::Borrowed("-")
+                });
+                ui.table_next_column();
+                let completed = lifetime.completed(challenge);
+                ui.text(loc!("stats_completed", completed));
+            }
+        }
+
+        ui.separator();
+        if let Some(_table) = ui.begin_table("resource stats", 5) {
+            let headers = [
+                Cow::Borrowed(""),
+                loc!("stats_gained"),
+                loc!("stats_spent"),
+                loc!("stats_lifetime_gained"),
+                loc!("stats_lifetime_spent"),
+            ];
+            for header in headers {
+                ui.table_next_column();
+                ui.text(header);
+            }
+            for res in ResourceType::iter() {
+                if lifetime.gained(res) == 0.0 && lifetime.spent(res) == 0.0 {
+                    continue;
+                }
+
+                ui.table_next_column();
+                ui.text(format!("{res}"));
+                let amounts = [
+                    run.gained(res),
+                    run.spent(res),
+                    lifetime.gained(res),
+                    lifetime.spent(res),
+                ];
+                for amount in amounts {
+                    ui.table_next_column();
+                    ui.text(format!("{}", amount.floor()));
+                }
+            }
+        }
+
+        ui.separator();
+        if let Some(_table) = ui.begin_table("action stats", 3) {
+            for header in [
+                loc!("stats_action"),
+                loc!("stats_bought"),
+                loc!("stats_lifetime_bought"),
+            ] {
+                ui.table_next_column();
+                ui.text(header);
+            }
+            for (id, count) in &lifetime.bought {
+                ui.table_next_column();
+                ui.text(action::find(id).map_or(Cow::from(id.as_str()), Action::title));
+                ui.table_next_column();
+                ui.text(format!("{}", run.bought(id)));
+                ui.table_next_column();
+                ui.text(format!("{count}"));
+            }
+        }
+    }
+
+    fn achievements(&mut self, ui: &Ui) {
+        let unlocked = self.profile.achievements.len();
+        let total = ACHIEVEMENTS.len();
+        ui.text(loc!("ui_achievements_unlocked", unlocked, total));
+        let bonus = ((achievement::mult(self) - 1.0) * 100.0).round();
+        ui.text(loc!("ui_achievements_bonus", bonus));
+
+        if let Some(_table) = ui.begin_table("achievements", 3) {
+            for achievement in ACHIEVEMENTS {
+                let time = self.profile.achievements.get(achievement.id);
+
+                ui.table_next_column();
+                if time.is_some() {
+                    ui.text(achievement.title());
+                } else {
+                    ui.text_disabled(achievement.title());
+                }
+                ui.table_next_column();
+                ui.text_wrapped(achievement.description());
+                ui.table_next_column();
+                match time {
+                    Some(&time) => ui.text(util::date(time)),
+                    None => ui.text_disabled(loc!("ui_locked")),
+                }
+            }
+        }
+    }
+
+    fn messages(&mut self, ui: &Ui) {
+        let log = &mut self.log;
+
+        for kind in MessageKind::iter() {
+            let mut shown = !log.hidden.contains(&kind);
+            if ui.checkbox(kind.title(), &mut shown) {
+                if shown {
+                    log.hidden.remove(&kind);
+                } else {
+                    log.hidden.insert(kind);
+                }
+            }
+            ui.same_line();
+        }
+        ui.new_line();
+
+        let (min, max) = message::LIMITS;
+        if ui.slider(loc!("ui_history"), min, max, &mut log.limit) {
+            log.truncate();
+        }
+
+        let height = ui.content_region_avail()[1] / 2.0;
+        ui.child_window("messages").size([0.0, height]).build(|| {
+            for message in log.visible() {
+                ui.text_disabled(message.timestamp());
+                ui.same_line();
+                let _color = ui.push_style_color(imgui::StyleColor::Text, message.kind.color());
+                ui.text_wrapped(message.text.render());
+            }
+        });
+    }
+
+    fn jobs(&mut self, ui: &Ui) {
+        let max = self.city.max_citizens();
+        let citizens = self.civic.citizens;
+        ui.text(loc!("ui_citizens", citizens, max));
+
+        if let Some(_table) = ui.begin_table("jobs", 3) {
+            ui.table_next_column();
+            ui.text(loc!("ui_unemployed"));
+            ui.table_next_column();
+            ui.text(format!("{}", self.civic.unemployed()));
+            ui.table_next_column();
+
+            for job in Job::iter() {
+                let Some(count) = self.civic.jobs[job] else {
+                    continue;
+                };
+                let max = job.max(&self.city);
+
+                ui.table_next_column();
+                ui.text(job.title());
+                ui.table_next_column();
+                match max {
+                    Some(max) => ui.text(format!("{count}/{max}")),
+                    None => ui.text(format!("{count}")),
+                }
+                ui.table_next_column();
+                if ui.small_button(format!("-##{job:?}")) {
+                    self.civic.unassign(job);
+                }
+                ui.same_line();
+                if ui.small_button(format!("+##{job:?}")) {
+                    self.civic.assign(job, max);
+                }
+            }
+        }
     }
 }
 
 impl Game {
-    fn become_sentient(&mut self) {
+    /// Throws away everything tied to the current run, the profile is left untouched
+    fn new_run(&mut self) {
+        // Every run gets its own seed so it is offered different planets
+        self.seed = self.rng.u64(..);
+        self.rng = Rng::with_seed(self.seed);
+
+        self.resources = Resources::new();
+        self.evolution = Evolution::new();
+        self.tech = Tech::new();
+        self.city = City::new();
+        self.civic = Civic::new();
+        self.storage = Storage::new();
+        self.market = Market::new();
+        self.power = Grid::new();
+        self.calendar = Calendar::new();
+        self.events = Events::new();
+        self.log = MessageLog::new();
+        self.stats = Stats::default();
+        self.rules = Rules::default();
+        self.universe = None;
+        self.planet = None;
+        self.race = Race::default();
+        ACTIONS.lock().unwrap().clear();
+    }
+
+    /// Offers the actions a loaded game had unlocked, nothing about them is saved
+    fn restore_actions(&self) {
+        let mut holder = ACTIONS.lock().unwrap();
+        holder.clear();
+
+        if self.evolution.sentience == Some(true) {
+            for action in [ACTION_FOOD, ACTION_LUMBER, ACTION_STONE, ACTION_KNOWLEDGE] {
+                holder.add(Category::City, action);
+            }
+            tech::restore(&self.tech, &mut holder);
+            return;
+        }
+
+        let evolution = &self.evolution;
+        if evolution.dna_unlocked {
+            holder.add(Category::Evolution, ACTION_DNA);
+        }
+
+        // Cells are offered for as long as they're counted
+        let cells = [
+            (evolution.membrane, ACTION_MEMBRANE),
+            (evolution.organelles, ACTION_ORGANELLES),
+            (evolution.nucleus, ACTION_NUCLEUS),
+            (evolution.eukaryotic_cell, ACTION_EUKARYOTIC_CELL),
+            (evolution.mitochondria, ACTION_MITOCHONDRIA),
+        ];
+        for (count, action) in cells {
+            if count.is_some() {
+                holder.add(Category::Evolution, action);
+            }
+        }
+
+        // Steps are offered until they're taken
+        let steps = [
+            (evolution.sexual_reproduction, ACTION_SEXUAL_REPRODUCTION),
+            (evolution.phagocytosis, ACTION_PHAGOCYTOSIS),
+            (evolution.chloroplasts, ACTION_CHLOROPLASTS),
+            (evolution.chitin, ACTION_CHITIN),
+            (evolution.multicellular, ACTION_MULTICELLULAR),
+            (evolution.bilateral_symmetry, ACTION_BILATERAL_SYMMETRY),
+            (evolution.poikilohydric, ACTION_POKILOHYDRIC),
+            (evolution.spores, ACTION_SPORES),
+            (evolution.bryophyte, ACTION_BRYOPHYTE),
+            (evolution.sentience, ACTION_SENTIENCE),
+        ];
+        for (taken, action) in steps {
+            if taken == Some(false) {
+                holder.add(Category::Evolution, action);
+            }
+        }
+    }
+
+    fn become_sentient(&mut self, holder: &mut ActionHolder) {
         self.resources.rna.display = false;
         self.resources.dna.display = false;
+        self.resources.knowledge.display = true;
+        self.resources.food.display = true;
+        self.resources.lumber.display = true;
+        self.resources.stone.display = true;
+
+        self.city.basic_housing = Some(1);
+        self.civic.citizens = 1;
+        // Enough to feed the founder until food can be gathered
+        self.mod_res(ResourceType::Food, 25.0, true, false);
+
+        holder.add(Category::City, ACTION_FOOD);
+        holder.add(Category::City, ACTION_LUMBER);
+        holder.add(Category::City, ACTION_STONE);
+        holder.add(Category::City, ACTION_KNOWLEDGE);
+        let species = Arg::Key(self.race.species.key().to_string());
+        self.log.push(MessageKind::Info, msg!("log_sentience", species));
+        stats::sentience(self);
+        tech::unlock(&self.tech, holder, &mut self.log);
+        achievement::check(self);
+    }
+
+    /// Reads the themes again and switches to the configured one, in case it changed on disk
+    fn load_themes(&mut self) {
+        self.theme_errors.clear();
+        self.themes = theme::themes(&mut self.theme_errors);
+        self.theme = theme::find(&self.themes, &self.settings.theme);
+        self.apply_theme = true;
+    }
+
+    /// Reloads the translations and themes that were edited, anything that fails to parse is logged
+    #[cfg(debug_assertions)]
+    fn hot_reload(&mut self) {
+        let Some(watcher) = self.watcher.as_mut() else {
+            return;
+        };
+        let changed = watcher.poll();
+        let is = |path: &std::path::PathBuf, extension: &str| path.extension().is_some_and(|ext| ext == extension);
+
+        if changed.iter().any(|path| is(path, "ftl")) {
+            lang::set_locale(&self.settings.locale);
+            self.log.push(MessageKind::Info, msg!("log_reloaded_lang"));
+            for error in lang::errors() {
+                self.log.push(MessageKind::Warning, message::Text::Raw(error));
+            }
+        }
+
+        if changed.iter().any(|path| is(path, "json")) {
+            self.load_themes();
+            self.log.push(MessageKind::Info, msg!("log_reloaded_themes"));
+            for error in self.theme_errors.clone() {
+                self.log.push(MessageKind::Warning, message::Text::Raw(error));
+            }
+        }
+    }
+
+    /// The shared multiplier every produced resource goes through
+    fn global_mult(&self) -> f32 {
+        self.civic.morale_mult()
+            * self.civic.government.production()
+            * self.events.production()
+            * prestige::mult(self)
+            * achievement::mult(self)
+            * self.universe().production()
+            * universe::mana_mult(self)
+    }
+
+    /// The universe of the run, standard until one is picked
+    pub(crate) fn universe(&self) -> Universe {
+        self.universe.unwrap_or(Universe::Standard)
     }
 
     fn diff_calc(&mut self, res: ResourceType, period: f32) {
@@ -393,10 +1575,15 @@ impl Game {
         }
 
         if !count.is_nan() {
+            let gained = count - self.resources[res].amount;
+            if gained > 0.0 {
+                stats::gain(self, res, gained);
+            }
+
             self.resources[res].amount = count;
-            if !notrack {
+            // mana shows how fast it is channelled, spending it doesn't pull the rate down
+            if !notrack && (res != ResourceType::Mana || val > 0.0) {
                 self.resources[res].delta += val;
-                // TODO: mana
             }
         }
 
@@ -0,0 +1,173 @@
+use crate::resource::{ResourceType, ResourceType::*};
+use crate::{stats, Game};
+use fastrand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Everything that can be traded along with its base price
+pub const GOODS: &[(ResourceType, f32)] = &[
+    (Food, 0.5),
+    (Lumber, 0.8),
+    (Stone, 0.9),
+    (Iron, 2.0),
+    (Coal, 1.5),
+    (Oil, 4.0),
+    (Uranium, 40.0),
+    (Plywood, 12.0),
+    (Brick, 10.0),
+    (WroughtIron, 20.0),
+    (SheetMetal, 35.0),
+];
+
+/// Selling only returns this much of the buying price
+const SELL_RATIO: f32 = 0.75;
+/// How far a price moves for every unit traded, relative to its base price
+const IMPACT: f32 = 0.0005;
+/// How far a price may drift from its base price in either direction
+const SPREAD: f32 = 4.0;
+/// How many trade routes are on offer
+const ROUTES: usize = 4;
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub struct TradeRoute {
+    pub res: ResourceType,
+    /// Units exchanged every second, positive amounts buy and negative amounts sell
+    pub amount: f32,
+    pub active: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Market {
+    pub prices: HashMap<ResourceType, f32>,
+    pub routes: Vec<TradeRoute>,
+    /// Drift is rolled from the seed and the number of drifts so far, leaving the game's rng alone
+    seed: u64,
+    drifts: u64,
+}
+
+impl Market {
+    pub fn new() -> Self {
+        Self {
+            prices: HashMap::new(),
+            routes: vec![],
+            seed: 0,
+            drifts: 0,
+        }
+    }
+
+    /// Opens the market with starting prices and trade routes rolled from `rng`
+    pub fn generate(rng: &Rng) -> Self {
+        let prices = GOODS
+            .iter()
+            .map(|&(res, base)| (res, base * (0.8 + rng.f32() * 0.4)))
+            .collect();
+
+        // Only raw materials are traded in bulk
+        let raw = [Food, Lumber, Stone, Iron];
+        let routes = (0..ROUTES)
+            .map(|_| {
+                let amount = (rng.u32(5..=20) as f32) / 10.0;
+                TradeRoute {
+                    res: raw[rng.usize(0..raw.len())],
+                    amount: if rng.bool() { amount } else { -amount },
+                    active: false,
+                }
+            })
+            .collect();
+
+        Self {
+            prices,
+            routes,
+            seed: rng.u64(..),
+            drifts: 0,
+        }
+    }
+
+    pub fn price(&self, res: ResourceType) -> f32 {
+        self.prices.get(&res).copied().unwrap_or_else(|| base(res))
+    }
+
+    pub fn sell_price(&self, res: ResourceType) -> f32 {
+        self.price(res) * SELL_RATIO
+    }
+
+    pub fn active_routes(&self) -> usize {
+        self.routes.iter().filter(|route| route.active).count()
+    }
+
+    /// Pushes the price up when buying (`volume > 0`) and down when selling
+    fn impact(&mut self, res: ResourceType, volume: f32) {
+        let base = base(res);
+        let price = self.prices.entry(res).or_insert(base);
+        *price = (*price + base * IMPACT * volume).clamp(base / SPREAD, base * SPREAD);
+    }
+}
+
+fn base(res: ResourceType) -> f32 {
+    GOODS
+        .iter()
+        .find(|(good, _)| *good == res)
+        .map_or(0.0, |(_, base)| *base)
+}
+
+/// Buys `amount` units with Money, returns `false` if it couldn't be afforded or stored
+pub fn buy(game: &mut Game, res: ResourceType, amount: f32) -> bool {
+    let cost = game.market.price(res) * amount;
+    let resource = &game.resources[res];
+    if !game.rules.trade()
+        || game.resources.money.amount < cost
+        || (resource.max != -1.0 && resource.amount + amount > resource.max)
+    {
+        return false;
+    }
+
+    game.mod_res(Money, -cost, false, false);
+    stats::spend(game, Money, cost);
+    game.mod_res(res, amount, false, false);
+    game.market.impact(res, amount);
+    true
+}
+
+/// Sells `amount` units for Money, returns `false` if there weren't enough to sell
+pub fn sell(game: &mut Game, res: ResourceType, amount: f32) -> bool {
+    if !game.rules.trade() || game.resources[res].amount < amount {
+        return false;
+    }
+
+    let income = game.market.sell_price(res) * amount;
+    game.mod_res(res, -amount, false, false);
+    stats::spend(game, res, amount);
+    game.mod_res(Money, income, false, false);
+    game.market.impact(res, -amount);
+    true
+}
+
+/// Lets every price wander randomly while slowly pulling it back towards its base price
+pub fn drift(game: &mut Game) {
+    let market = &mut game.market;
+    let rng = Rng::with_seed(market.seed.wrapping_add(market.drifts));
+    market.drifts += 1;
+
+    for &(res, base) in GOODS {
+        let price = market.prices.entry(res).or_insert(base);
+        let wander = (rng.f32() - 0.5) * 0.04;
+        *price += *price * wander + (base - *price) * 0.01;
+        *price = price.clamp(base / SPREAD, base * SPREAD);
+    }
+}
+
+/// Runs every active trade route, routes that can't be paid for or stored just skip the tick
+pub fn trade(game: &mut Game, time_mult: f32) {
+    for idx in 0..game.market.routes.len() {
+        let TradeRoute { res, amount, active } = game.market.routes[idx];
+        if !active {
+            continue;
+        }
+
+        if amount > 0.0 {
+            buy(game, res, amount * time_mult);
+        } else {
+            sell(game, res, -amount * time_mult);
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{lang, loc, util};
+use fluent::{FluentArgs, FluentValue};
+use serde::{Deserialize, Serialize};
+use std::{
+    borrow::Cow,
+    collections::{HashSet, VecDeque},
+};
+use strum::{EnumIter, IntoEnumIterator};
+
+/// How many messages are kept by default
+pub const DEFAULT_LIMIT: usize = 100;
+/// Bounds of the history limit that can be picked from the panel
+pub const LIMITS: (usize, usize) = (10, 1000);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, Serialize, Deserialize)]
+pub enum MessageKind {
+    Info,
+    Warning,
+    Achievement,
+    Event,
+}
+
+impl MessageKind {
+    pub fn iter() -> impl Iterator<Item = MessageKind> {
+        <Self as IntoEnumIterator>::iter()
+    }
+
+    /// Translation key of the title
+    pub fn key(&self) -> &'static str {
+        match self {
+            MessageKind::Info => "message_info",
+            MessageKind::Warning => "message_warning",
+            MessageKind::Achievement => "message_achievement",
+            MessageKind::Event => "message_event",
+        }
+    }
+
+    pub fn title(&self) -> Cow<'static, str> {
+        loc!(self.key())
+    }
+
+    pub fn color(&self) -> [f32; 4] {
+        match self {
+            MessageKind::Info => [1.0, 1.0, 1.0, 1.0],
+            MessageKind::Warning => [1.0, 0.4, 0.4, 1.0],
+            MessageKind::Achievement => [1.0, 0.85, 0.3, 1.0],
+            MessageKind::Event => [0.5, 0.8, 1.0, 1.0],
+        }
+    }
+}
+
+/// An argument of a logged message
+#[derive(Clone, Serialize, Deserialize)]
+pub enum Arg {
+    Number(f64),
+    Text(String),
+    /// Translated along with the message, like the title of an action
+    Key(String),
+}
+
+impl From<u32> for Arg {
+    fn from(number: u32) -> Self {
+        Arg::Number(number as f64)
+    }
+}
+
+impl From<String> for Arg {
+    fn from(text: String) -> Self {
+        Arg::Text(text)
+    }
+}
+
+/// What a message says, kept untranslated so the history follows locale switches and reloads
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Text {
+    Key {
+        key: String,
+        args: Vec<(String, Arg)>,
+    },
+    /// Shown as is, like parse errors
+    Raw(String),
+}
+
+impl Text {
+    pub fn render(&self) -> Cow<str> {
+        let (key, args) = match self {
+            Text::Key { key, args } => (key, args),
+            Text::Raw(text) => return Cow::Borrowed(text),
+        };
+
+        let mut fluent_args = FluentArgs::new();
+        for (name, arg) in args {
+            let value = match arg {
+                Arg::Number(number) => FluentValue::from(*number),
+                Arg::Text(text) => FluentValue::from(text.as_str()),
+                Arg::Key(key) => FluentValue::from(lang::loc(key, None).into_owned()),
+            };
+            fluent_args.set(name.as_str(), value);
+        }
+        Cow::Owned(lang::loc(key, Some(&fluent_args)).into_owned())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Message {
+    pub kind: MessageKind,
+    pub text: Text,
+    /// Seconds since the unix epoch
+    pub time: u64,
+}
+
+impl Message {
+    /// Time of day the message was logged at, as `hh:mm:ss` in UTC
+    pub fn timestamp(&self) -> String {
+        let secs = self.time % 86400;
+        format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MessageLog {
+    /// Oldest messages first
+    pub messages: VecDeque<Message>,
+    pub limit: usize,
+    /// Kinds of messages that aren't shown in the panel
+    pub hidden: HashSet<MessageKind>,
+}
+
+impl MessageLog {
+    pub fn new() -> Self {
+        Self {
+            messages: VecDeque::new(),
+            limit: DEFAULT_LIMIT,
+            hidden: HashSet::new(),
+        }
+    }
+
+    pub fn push(&mut self, kind: MessageKind, text: Text) {
+        self.messages.push_back(Message {
+            kind,
+            text,
+            time: util::now(),
+        });
+        self.truncate();
+    }
+
+    /// Drops the oldest messages until the history fits the limit
+    pub fn truncate(&mut self) {
+        while self.messages.len() > self.limit {
+            self.messages.pop_front();
+        }
+    }
+
+    /// Messages of the kinds that aren't filtered out, newest first
+    pub fn visible(&self) -> impl Iterator<Item = &Message> {
+        self.messages
+            .iter()
+            .rev()
+            .filter(|message| !self.hidden.contains(&message.kind))
+    }
+}
+
+/// Like [`loc!`], but the key and arguments are kept for the message to be translated whenever it's drawn
+#[macro_export]
+macro_rules! msg {
+    ($key:expr) => {
+        crate::message::Text::Key {
+            key: $key.to_string(),
+            args: vec![],
+        }
+    };
+
+    ($key:expr, $( $arg:expr ),+) => {
+        crate::message::Text::Key {
+            key: $key.to_string(),
+            args: vec![$( (stringify!($arg).to_string(), crate::message::Arg::from($arg)) ),+],
+        }
+    };
+}
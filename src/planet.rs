@@ -0,0 +1,125 @@
+use crate::civic::Job;
+use crate::loc;
+use crate::race::Species;
+use fastrand::Rng;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// How many planets are offered at the start of a run
+pub const CHOICES: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Biome {
+    Grassland,
+    Oceanic,
+    Desert,
+    Volcanic,
+    Tundra,
+    Forest,
+}
+
+impl Biome {
+    pub const ALL: [Biome; 6] = [
+        Biome::Grassland,
+        Biome::Oceanic,
+        Biome::Desert,
+        Biome::Volcanic,
+        Biome::Tundra,
+        Biome::Forest,
+    ];
+
+    /// Translation key of the title
+    pub fn title_key(&self) -> &'static str {
+        match self {
+            Biome::Grassland => "biome_grassland_title",
+            Biome::Oceanic => "biome_oceanic_title",
+            Biome::Desert => "biome_desert_title",
+            Biome::Volcanic => "biome_volcanic_title",
+            Biome::Tundra => "biome_tundra_title",
+            Biome::Forest => "biome_forest_title",
+        }
+    }
+
+    pub fn title(&self) -> Cow<'static, str> {
+        loc!(self.title_key())
+    }
+
+    /// Translation key of the description
+    pub fn desc_key(&self) -> &'static str {
+        match self {
+            Biome::Grassland => "biome_grassland_desc",
+            Biome::Oceanic => "biome_oceanic_desc",
+            Biome::Desert => "biome_desert_desc",
+            Biome::Volcanic => "biome_volcanic_desc",
+            Biome::Tundra => "biome_tundra_desc",
+            Biome::Forest => "biome_forest_desc",
+        }
+    }
+
+    pub fn description(&self) -> Cow<'static, str> {
+        loc!(self.desc_key())
+    }
+
+    /// Multiplier the biome applies to the output of a job
+    pub fn job_mult(&self, job: Job) -> f32 {
+        match (self, job) {
+            (Biome::Grassland, Job::Farmer) => 1.2,
+            (Biome::Oceanic, Job::Farmer) => 1.1,
+            (Biome::Oceanic, Job::QuarryWorker) => 0.9,
+            (Biome::Desert, Job::Farmer) => 0.75,
+            (Biome::Desert, Job::QuarryWorker) => 1.2,
+            (Biome::Volcanic, Job::Farmer) => 0.9,
+            (Biome::Volcanic, Job::Miner | Job::CoalMiner) => 1.2,
+            (Biome::Tundra, Job::Farmer) => 0.75,
+            (Biome::Tundra, Job::Miner) => 1.1,
+            (Biome::Forest, Job::Lumberjack) => 1.2,
+            _ => 1.0,
+        }
+    }
+
+    /// Whether `species` can evolve on this biome, every biome allows at least one fungi and one plant
+    pub fn allows(&self, species: Species) -> bool {
+        !matches!(
+            (self, species),
+            (Biome::Oceanic, Species::Cacti)
+                | (Biome::Desert, Species::Molding | Species::Entish)
+                | (Biome::Volcanic, Species::Shroomi | Species::Pinguicula)
+                | (Biome::Tundra, Species::Sporgar | Species::Cacti)
+                | (Biome::Forest, Species::Cacti)
+        )
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Planet {
+    pub name: String,
+    pub biome: Biome,
+    /// Days it takes to orbit the sun, this is how long a year lasts
+    pub orbit: u32,
+}
+
+impl Planet {
+    fn generate(rng: &Rng) -> Self {
+        const SYLLABLES: &[&str] = &[
+            "ka", "zu", "vor", "el", "ix", "tan", "mo", "re", "qua", "dis", "ol", "nym",
+        ];
+
+        let mut name = String::new();
+        for _ in 0..rng.usize(2..=3) {
+            name.push_str(SYLLABLES[rng.usize(0..SYLLABLES.len())]);
+        }
+        name[..1].make_ascii_uppercase();
+
+        Self {
+            name,
+            biome: Biome::ALL[rng.usize(0..Biome::ALL.len())],
+            orbit: rng.u32(200..=600),
+        }
+    }
+}
+
+/// The planets offered for a run, always the same ones for the same seed
+pub fn choices(seed: u64) -> Vec<Planet> {
+    let rng = Rng::with_seed(seed);
+    (0..CHOICES).map(|_| Planet::generate(&rng)).collect()
+}
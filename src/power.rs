@@ -0,0 +1,167 @@
+use crate::action::{Action, ACTION_COAL_POWER, ACTION_FISSION_POWER, ACTION_FOUNDRY, ACTION_MINE, ACTION_OIL_POWER};
+use crate::city::City;
+use crate::resource::{Cost, ResourceType::*};
+use crate::Game;
+use serde::{Deserialize, Serialize};
+use std::{cmp::Reverse, collections::HashMap};
+
+/// A structure that is hooked up to the power grid
+pub struct PowerStructure {
+    pub action: Action,
+    pub count: fn(&City) -> Option<u32>,
+    /// Power generated (positive) or used (negative) by a single running copy, in MW
+    pub power: f32,
+    /// Fuel a single running generator burns every second
+    pub fuel: Option<Cost>,
+    /// Consumers with a higher priority keep their power longer during a brownout
+    pub priority: u32,
+}
+
+/// How much more a worker gets done in a consumer that is running
+pub const POWERED_BOOST: f32 = 0.5;
+
+pub const GRID: &[PowerStructure] = &[
+    PowerStructure {
+        action: ACTION_COAL_POWER,
+        count: |city| city.coal_power,
+        power: 5.0,
+        fuel: Some(Cost {
+            resource: Coal,
+            amount: 0.35,
+        }),
+        priority: 0,
+    },
+    PowerStructure {
+        action: ACTION_OIL_POWER,
+        count: |city| city.oil_power,
+        power: 6.0,
+        fuel: Some(Cost {
+            resource: Oil,
+            amount: 0.65,
+        }),
+        priority: 0,
+    },
+    PowerStructure {
+        action: ACTION_FISSION_POWER,
+        count: |city| city.fission_power,
+        power: 14.0,
+        fuel: Some(Cost {
+            resource: Uranium,
+            amount: 0.1,
+        }),
+        priority: 0,
+    },
+    PowerStructure {
+        action: ACTION_FOUNDRY,
+        count: |city| city.foundry,
+        power: -2.0,
+        fuel: None,
+        priority: 2,
+    },
+    PowerStructure {
+        action: ACTION_MINE,
+        count: |city| city.mine,
+        power: -1.0,
+        fuel: None,
+        priority: 1,
+    },
+];
+
+pub fn find(id: &str) -> Option<&'static PowerStructure> {
+    GRID.iter().find(|structure| structure.action.id == id)
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Grid {
+    /// Copies of each structure the player has switched off
+    pub off: HashMap<String, u32>,
+    /// Copies of each structure that were running after the last balance
+    pub running: HashMap<String, u32>,
+    pub supply: f32,
+    pub demand: f32,
+}
+
+impl Grid {
+    pub fn new() -> Self {
+        Self {
+            off: HashMap::new(),
+            running: HashMap::new(),
+            supply: 0.0,
+            demand: 0.0,
+        }
+    }
+
+    pub fn off(&self, id: &str) -> u32 {
+        self.off.get(id).copied().unwrap_or(0)
+    }
+
+    pub fn running(&self, id: &str) -> u32 {
+        self.running.get(id).copied().unwrap_or(0)
+    }
+
+    pub fn switch_on(&mut self, id: &str) {
+        if let Some(off) = self.off.get_mut(id) {
+            *off = off.saturating_sub(1);
+        }
+    }
+
+    pub fn switch_off(&mut self, id: &str, built: u32) {
+        let off = self.off.entry(id.to_string()).or_default();
+        if *off < built {
+            *off += 1;
+        }
+    }
+
+    /// Whether consumers are asking for more power than the generators produce
+    pub fn brownout(&self) -> bool {
+        self.demand > self.supply
+    }
+}
+
+/// Of the workers a consumer employs, one per copy, how many work in a copy that is running
+pub fn powered(game: &Game, id: &str, workers: u32) -> u32 {
+    workers.min(game.power.running(id))
+}
+
+/// Copies of a structure that are built and switched on
+pub fn enabled(game: &Game, structure: &PowerStructure) -> u32 {
+    let built = (structure.count)(&game.city).unwrap_or(0);
+    built.saturating_sub(game.power.off(structure.action.id))
+}
+
+/// Burns fuel for every generator that is switched on, then hands the power out to consumers by priority
+pub fn balance(game: &mut Game, time_mult: f32) {
+    let mut running = HashMap::new();
+
+    let mut supply = 0.0;
+    for structure in GRID.iter().filter(|structure| structure.power > 0.0) {
+        let mut count = enabled(game, structure);
+        if let Some(fuel) = structure.fuel {
+            let burn = fuel.amount * time_mult;
+            count = count.min((game.resources[fuel.resource].amount / burn).floor() as u32);
+            game.mod_res(fuel.resource, -burn * count as f32, false, false);
+        }
+
+        supply += count as f32 * structure.power;
+        running.insert(structure.action.id.to_string(), count);
+    }
+
+    let mut consumers: Vec<_> = GRID.iter().filter(|structure| structure.power < 0.0).collect();
+    consumers.sort_by_key(|structure| Reverse(structure.priority));
+
+    let mut demand = 0.0;
+    let mut remaining = supply;
+    for structure in consumers {
+        let usage = -structure.power;
+        let enabled = enabled(game, structure);
+        let count = enabled.min((remaining / usage).floor() as u32);
+
+        demand += enabled as f32 * usage;
+        remaining -= count as f32 * usage;
+        running.insert(structure.action.id.to_string(), count);
+    }
+
+    game.power.running = running;
+    game.power.supply = supply;
+    game.power.demand = demand;
+}
@@ -0,0 +1,48 @@
+use crate::action::Action;
+use crate::message::MessageKind;
+use crate::resource::ResourceType::Knowledge;
+use crate::{loc, msg, stats, Game};
+use std::borrow::Cow;
+
+/// Production bonus granted by every plasmid
+const PLASMID_BONUS: f32 = 0.01;
+
+/// Plasmids a reset would award right now, grows with the population, the techs researched and the knowledge gained,
+/// then scaled by the universe
+pub fn gain(game: &Game) -> u32 {
+    let citizens = game.civic.citizens as f32;
+    let techs = game.tech.completed().count() as f32;
+    let knowledge = game.stats.gained(Knowledge) as f32;
+    let plasmids = (citizens + techs) / 2.0 + knowledge / 1000.0;
+    (plasmids * game.universe().plasmids()).floor() as u32
+}
+
+/// Permanent production multiplier from the plasmids in the profile
+pub fn mult(game: &Game) -> f32 {
+    1.0 + game.profile.plasmids as f32 * PLASMID_BONUS
+}
+
+/// Ends the run, banking its plasmids in the profile before starting over
+pub fn reset(game: &mut Game) {
+    let plasmids = gain(game);
+    game.profile.plasmids += plasmids;
+    game.profile.resets += 1;
+    stats::complete(game);
+    game.profile.save();
+
+    game.new_run();
+    game.log.push(MessageKind::Info, msg!("log_prestige", plasmids));
+}
+
+pub const ACTION_MAD: Action = Action {
+    id: "prestige-mad",
+    title: "prestige_mad_title",
+    desc: "prestige_mad_desc",
+    effect: Some(|game| {
+        let plasmids = gain(game);
+        loc!("prestige_mad_effect", plasmids)
+    }),
+    cost: None,
+    action: |_, game| reset(game),
+    count: None,
+};
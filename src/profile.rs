@@ -0,0 +1,36 @@
+use crate::stats::Stats;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs::File};
+
+const PROFILE_FILE: &str = "profile.json";
+
+/// Progress that outlives a single run, kept in its own file so resets and new games don't touch it
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Profile {
+    /// Prestige currency earned by resetting
+    pub plasmids: u32,
+    /// How many times a run was reset through prestige
+    pub resets: u32,
+    /// Lifetime totals across every run
+    pub stats: Stats,
+    /// Unlocked achievements along with when they were unlocked, in seconds since the unix epoch
+    pub achievements: BTreeMap<String, u64>,
+}
+
+impl Profile {
+    /// Reads the profile from disk, starting a fresh one if there is none yet
+    pub fn load() -> Self {
+        std::fs::read_to_string(PROFILE_FILE)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        let x = serde_json::to_vec_pretty(self).unwrap();
+        let mut file = File::create(PROFILE_FILE).unwrap();
+        use std::io::Write;
+        file.write(&x).unwrap();
+    }
+}
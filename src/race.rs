@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
+use strum::EnumIter;
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, Serialize, Deserialize)]
 pub enum Species {
     Protoplasm,
     // Fungi
@@ -13,6 +14,21 @@ pub enum Species {
     Pinguicula,
 }
 
+impl Species {
+    /// Translation key of the species name
+    pub fn key(&self) -> &'static str {
+        match self {
+            Species::Protoplasm => "species_protoplasm_title",
+            Species::Sporgar => "species_sporgar_title",
+            Species::Shroomi => "species_shroomi_title",
+            Species::Molding => "species_molding_title",
+            Species::Entish => "species_entish_title",
+            Species::Cacti => "species_cacti_title",
+            Species::Pinguicula => "species_pinguicula_title",
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct Race {
     pub species: Species,
@@ -1,3 +1,4 @@
+use crate::loc;
 use serde::{Deserialize, Serialize};
 use strum::{EnumIter, IntoEnumIterator};
 use std::{
@@ -5,25 +6,61 @@ use std::{
     ops::{Index, IndexMut},
 };
 
-#[derive(Clone, Copy, EnumIter)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, Serialize, Deserialize)]
 pub enum ResourceType {
     RNA,
     DNA,
+    Knowledge,
+    Food,
+    Lumber,
+    Stone,
+    Money,
+    Iron,
+    Coal,
+    Oil,
+    Uranium,
+    Mana,
+    Plywood,
+    Brick,
+    WroughtIron,
+    SheetMetal,
+    Crates,
+    Containers,
 }
 
 impl ResourceType {
     pub fn iter() -> impl Iterator<Item = ResourceType> {
         <Self as IntoEnumIterator>::iter()
     }
+
+    /// Translation key of the resource name
+    pub fn key(&self) -> &'static str {
+        match self {
+            ResourceType::RNA => "resource_RNA_name",
+            ResourceType::DNA => "resource_DNA_name",
+            ResourceType::Knowledge => "resource_Knowledge_name",
+            ResourceType::Food => "resource_Food_name",
+            ResourceType::Lumber => "resource_Lumber_name",
+            ResourceType::Stone => "resource_Stone_name",
+            ResourceType::Money => "resource_Money_name",
+            ResourceType::Iron => "resource_Iron_name",
+            ResourceType::Coal => "resource_Coal_name",
+            ResourceType::Oil => "resource_Oil_name",
+            ResourceType::Uranium => "resource_Uranium_name",
+            ResourceType::Mana => "resource_Mana_name",
+            ResourceType::Plywood => "resource_Plywood_name",
+            ResourceType::Brick => "resource_Brick_name",
+            ResourceType::WroughtIron => "resource_WroughtIron_name",
+            ResourceType::SheetMetal => "resource_SheetMetal_name",
+            ResourceType::Crates => "resource_Crates_name",
+            ResourceType::Containers => "resource_Containers_name",
+        }
+    }
 }
 
 impl fmt::Display for ResourceType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let name = match self {
-            ResourceType::RNA => "RNA",
-            ResourceType::DNA => "DNA",
-        };
-        write!(f, "{name}")
+        write!(f, "{}", loc!(self.key()))
     }
 }
 
@@ -50,7 +87,7 @@ impl Resource {
     }
 
     pub fn is_full(&self) -> bool {
-        self.amount >= self.max
+        self.max != -1.0 && self.amount >= self.max
     }
 }
 
@@ -59,6 +96,22 @@ impl Resource {
 pub struct Resources {
     pub rna: Resource,
     pub dna: Resource,
+    pub knowledge: Resource,
+    pub food: Resource,
+    pub lumber: Resource,
+    pub stone: Resource,
+    pub money: Resource,
+    pub iron: Resource,
+    pub coal: Resource,
+    pub oil: Resource,
+    pub uranium: Resource,
+    pub mana: Resource,
+    pub plywood: Resource,
+    pub brick: Resource,
+    pub wrought_iron: Resource,
+    pub sheet_metal: Resource,
+    pub crates: Resource,
+    pub containers: Resource,
 }
 
 impl Resources {
@@ -66,6 +119,22 @@ impl Resources {
         Self {
             rna: Resource::new(0.0, 100.0, 1.0, true),
             dna: Resource::new(0.0, 100.0, 1.0, false),
+            knowledge: Resource::new(0.0, 100.0, 1.0, false),
+            food: Resource::new(0.0, 250.0, 1.0, false),
+            lumber: Resource::new(0.0, 200.0, 1.0, false),
+            stone: Resource::new(0.0, 200.0, 1.0, false),
+            money: Resource::new(0.0, 1000.0, 1.0, false),
+            iron: Resource::new(0.0, 100.0, 1.0, false),
+            coal: Resource::new(0.0, 50.0, 1.0, false),
+            oil: Resource::new(0.0, 0.0, 1.0, false),
+            uranium: Resource::new(0.0, 10.0, 1.0, false),
+            mana: Resource::new(0.0, 100.0, 1.0, false),
+            plywood: Resource::new(0.0, -1.0, 1.0, false),
+            brick: Resource::new(0.0, -1.0, 1.0, false),
+            wrought_iron: Resource::new(0.0, -1.0, 1.0, false),
+            sheet_metal: Resource::new(0.0, -1.0, 1.0, false),
+            crates: Resource::new(0.0, 0.0, 1.0, false),
+            containers: Resource::new(0.0, 0.0, 1.0, false),
         }
     }
 }
@@ -77,6 +146,22 @@ impl Index<ResourceType> for Resources {
         match index {
             ResourceType::RNA => &self.rna,
             ResourceType::DNA => &self.dna,
+            ResourceType::Knowledge => &self.knowledge,
+            ResourceType::Food => &self.food,
+            ResourceType::Lumber => &self.lumber,
+            ResourceType::Stone => &self.stone,
+            ResourceType::Money => &self.money,
+            ResourceType::Iron => &self.iron,
+            ResourceType::Coal => &self.coal,
+            ResourceType::Oil => &self.oil,
+            ResourceType::Uranium => &self.uranium,
+            ResourceType::Mana => &self.mana,
+            ResourceType::Plywood => &self.plywood,
+            ResourceType::Brick => &self.brick,
+            ResourceType::WroughtIron => &self.wrought_iron,
+            ResourceType::SheetMetal => &self.sheet_metal,
+            ResourceType::Crates => &self.crates,
+            ResourceType::Containers => &self.containers,
         }
     }
 }
@@ -86,6 +171,22 @@ impl IndexMut<ResourceType> for Resources {
         match index {
             ResourceType::RNA => &mut self.rna,
             ResourceType::DNA => &mut self.dna,
+            ResourceType::Knowledge => &mut self.knowledge,
+            ResourceType::Food => &mut self.food,
+            ResourceType::Lumber => &mut self.lumber,
+            ResourceType::Stone => &mut self.stone,
+            ResourceType::Money => &mut self.money,
+            ResourceType::Iron => &mut self.iron,
+            ResourceType::Coal => &mut self.coal,
+            ResourceType::Oil => &mut self.oil,
+            ResourceType::Uranium => &mut self.uranium,
+            ResourceType::Mana => &mut self.mana,
+            ResourceType::Plywood => &mut self.plywood,
+            ResourceType::Brick => &mut self.brick,
+            ResourceType::WroughtIron => &mut self.wrought_iron,
+            ResourceType::SheetMetal => &mut self.sheet_metal,
+            ResourceType::Crates => &mut self.crates,
+            ResourceType::Containers => &mut self.containers,
         }
     }
 }
@@ -0,0 +1,134 @@
+use crate::fonts::Glyphs;
+use crate::loc;
+use crate::theme::DEFAULT_THEME;
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fs::File};
+use strum::{EnumIter, IntoEnumIterator};
+
+const SETTINGS_FILE: &str = "settings.json";
+/// Where imgui keeps the window positions and docking layout
+pub const LAYOUT_FILE: &str = "layout.ini";
+/// Locale used when none is configured
+pub const DEFAULT_LOCALE: &str = "en-US";
+
+#[derive(Debug, Clone, Copy, PartialEq, EnumIter, Serialize, Deserialize)]
+pub enum Notation {
+    Standard,
+    Short,
+    Scientific,
+    Engineering,
+}
+
+impl Notation {
+    pub fn iter() -> impl Iterator<Item = Notation> {
+        <Self as IntoEnumIterator>::iter()
+    }
+
+    /// Translation key of the title
+    pub fn key(&self) -> &'static str {
+        match self {
+            Notation::Standard => "notation_standard",
+            Notation::Short => "notation_short",
+            Notation::Scientific => "notation_scientific",
+            Notation::Engineering => "notation_engineering",
+        }
+    }
+
+    pub fn title(&self) -> Cow<'static, str> {
+        loc!(self.key())
+    }
+
+    /// Formats a number for display, anything below a thousand is shown as is
+    pub fn format(&self, value: f32) -> String {
+        let value = (value * 100.0).round() / 100.0;
+        // NaN and infinity have no exponent to pick a suffix with
+        if !value.is_finite() || value.abs() < 1000.0 || *self == Notation::Standard {
+            return format!("{value}");
+        }
+
+        let exponent = value.abs().log10().floor() as i32;
+        match self {
+            Notation::Standard => unreachable!(),
+            Notation::Short => {
+                const SUFFIXES: &[&str] = &["K", "M", "B", "T", "Qa", "Qi", "Sx", "Sp", "Oc", "No"];
+                let group = (exponent / 3).min(SUFFIXES.len() as i32);
+                let mantissa = value / 10f32.powi(group * 3);
+                format!("{mantissa:.2}{}", SUFFIXES[group as usize - 1])
+            }
+            Notation::Scientific => {
+                let mantissa = value / 10f32.powi(exponent);
+                format!("{mantissa:.2}e{exponent}")
+            }
+            Notation::Engineering => {
+                let exponent = exponent - exponent % 3;
+                let mantissa = value / 10f32.powi(exponent);
+                format!("{mantissa:.2}e{exponent}")
+            }
+        }
+    }
+}
+
+/// Player preferences, kept in their own file so changing them never touches the save
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub notation: Notation,
+    /// Seconds between autosaves
+    pub autosave: u32,
+    /// Multiplier applied to the size of everything drawn
+    pub ui_scale: f32,
+    /// Size of the font in pixels
+    pub font_size: f32,
+    /// File in [`crate::fonts::FONTS_DIR`], `None` for the built in font
+    pub font: Option<String>,
+    /// Font merged into the main one for the glyphs it lacks
+    pub fallback_font: Option<String>,
+    pub glyphs: Glyphs,
+    /// Name of the theme, see [`crate::theme`]
+    pub theme: String,
+    pub locale: String,
+    /// Whether destructive actions ask before going through
+    pub confirm: bool,
+    /// Whether panels can be moved, resized and docked instead of being pinned in place
+    pub free_layout: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            notation: Notation::Short,
+            autosave: 5,
+            ui_scale: 1.0,
+            font_size: 13.0,
+            font: None,
+            fallback_font: None,
+            glyphs: Glyphs::Latin,
+            theme: DEFAULT_THEME.to_string(),
+            locale: DEFAULT_LOCALE.to_string(),
+            confirm: true,
+            free_layout: false,
+        }
+    }
+}
+
+impl Settings {
+    /// Reads the settings from disk, falling back to the defaults if there are none yet
+    pub fn load() -> Self {
+        std::fs::read_to_string(SETTINGS_FILE)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        let x = serde_json::to_vec_pretty(self).unwrap();
+        let mut file = File::create(SETTINGS_FILE).unwrap();
+        use std::io::Write;
+        file.write(&x).unwrap();
+    }
+
+    /// Pushes the settings that can change at runtime into the imgui context
+    pub fn apply(&self, imgui: &mut imgui::Context, hidpi: f64) {
+        imgui.io_mut().font_global_scale = self.ui_scale / hidpi as f32;
+    }
+}
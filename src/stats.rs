@@ -0,0 +1,89 @@
+use crate::challenge::Challenge;
+use crate::race::Species;
+use crate::resource::ResourceType;
+use crate::Game;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Tracked once for the current run and once for the lifetime totals kept in the profile
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Stats {
+    pub gained: HashMap<ResourceType, f64>,
+    /// Resources paid for actions, crafts and trades
+    pub spent: HashMap<ResourceType, f64>,
+    /// How many times every action was bought, by action id
+    pub bought: BTreeMap<String, u32>,
+    /// Seconds played
+    pub time: u64,
+    /// Seconds it took to reach sentience, the fastest run for the lifetime totals
+    pub evolution_time: Option<u64>,
+    pub species: HashSet<Species>,
+    /// Runs reset while a challenge was active
+    pub challenges: HashMap<Challenge, u32>,
+}
+
+impl Stats {
+    pub fn gained(&self, res: ResourceType) -> f64 {
+        self.gained.get(&res).copied().unwrap_or(0.0)
+    }
+
+    pub fn spent(&self, res: ResourceType) -> f64 {
+        self.spent.get(&res).copied().unwrap_or(0.0)
+    }
+
+    pub fn bought(&self, id: &str) -> u32 {
+        self.bought.get(id).copied().unwrap_or(0)
+    }
+
+    pub fn completed(&self, challenge: Challenge) -> u32 {
+        self.challenges.get(&challenge).copied().unwrap_or(0)
+    }
+}
+
+/// Runs the same update on the run stats and the lifetime stats
+fn record(game: &mut Game, f: impl Fn(&mut Stats)) {
+    f(&mut game.stats);
+    f(&mut game.profile.stats);
+}
+
+pub fn gain(game: &mut Game, res: ResourceType, amount: f32) {
+    record(game, |stats| *stats.gained.entry(res).or_default() += amount as f64);
+}
+
+pub fn spend(game: &mut Game, res: ResourceType, amount: f32) {
+    record(game, |stats| *stats.spent.entry(res).or_default() += amount as f64);
+}
+
+pub fn buy(game: &mut Game, id: &str) {
+    record(game, |stats| *stats.bought.entry(id.to_string()).or_default() += 1);
+}
+
+pub fn tick(game: &mut Game, secs: u64) {
+    record(game, |stats| stats.time += secs);
+}
+
+/// Stamps how long the run took to evolve and adds its species to the ones reached
+pub fn sentience(game: &mut Game) {
+    let time = game.stats.time;
+    let species = game.race.species;
+    game.stats.evolution_time = Some(time);
+    game.stats.species.insert(species);
+
+    let lifetime = &mut game.profile.stats;
+    lifetime.evolution_time = Some(lifetime.evolution_time.map_or(time, |best| best.min(time)));
+    lifetime.species.insert(species);
+}
+
+/// Counts the run as completed for every challenge it was played under
+pub fn complete(game: &mut Game) {
+    let challenges: Vec<Challenge> = game.rules.challenges.iter().copied().collect();
+    for challenge in challenges {
+        record(game, |stats| *stats.challenges.entry(challenge).or_default() += 1);
+    }
+}
+
+/// Formats seconds as `hh:mm:ss`
+pub fn duration(secs: u64) -> String {
+    format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
@@ -0,0 +1,173 @@
+use crate::resource::{ResourceType, ResourceType::*};
+use crate::Game;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Extra room a single crate gives the resource it is assigned to
+pub const CRATE_SIZE: f32 = 350.0;
+/// Extra room a single container gives the resource it is assigned to
+pub const CONTAINER_SIZE: f32 = 800.0;
+
+#[derive(Serialize, Deserialize)]
+pub struct Storage {
+    pub crates: HashMap<ResourceType, u32>,
+    pub containers: HashMap<ResourceType, u32>,
+}
+
+impl Storage {
+    pub fn new() -> Self {
+        Self {
+            crates: HashMap::new(),
+            containers: HashMap::new(),
+        }
+    }
+
+    /// How many crates (`kind == Crates`) or containers (`kind == Containers`) are assigned to `res`
+    pub fn assigned(&self, kind: ResourceType, res: ResourceType) -> u32 {
+        self.assignments(kind).get(&res).copied().unwrap_or(0)
+    }
+
+    fn assignments(&self, kind: ResourceType) -> &HashMap<ResourceType, u32> {
+        match kind {
+            Containers => &self.containers,
+            _ => &self.crates,
+        }
+    }
+
+    fn assignments_mut(&mut self, kind: ResourceType) -> &mut HashMap<ResourceType, u32> {
+        match kind {
+            Containers => &mut self.containers,
+            _ => &mut self.crates,
+        }
+    }
+}
+
+/// Something that raises resource caps for every copy of it that exists
+struct CapSource {
+    count: fn(&Game) -> Option<u32>,
+    amount: fn(&Game, ResourceType) -> f32,
+}
+
+const SOURCES: &[CapSource] = &[
+    CapSource {
+        count: |game| game.evolution.membrane,
+        amount: |game, res| match res {
+            RNA => (game.evolution.mitochondria.unwrap_or(0) * 5 + 5) as f32,
+            _ => 0.0,
+        },
+    },
+    CapSource {
+        count: |game| game.evolution.eukaryotic_cell,
+        amount: |game, res| match res {
+            DNA => (game.evolution.mitochondria.unwrap_or(0) * 10 + 10) as f32,
+            _ => 0.0,
+        },
+    },
+    CapSource {
+        count: |game| game.city.university,
+        amount: |_, res| match res {
+            Knowledge => 500.0,
+            _ => 0.0,
+        },
+    },
+    CapSource {
+        count: |game| game.city.shed,
+        amount: |_, res| match res {
+            Lumber | Stone => 300.0,
+            Iron | Coal => 100.0,
+            Crates => 10.0,
+            _ => 0.0,
+        },
+    },
+    CapSource {
+        count: |game| game.city.silo,
+        amount: |_, res| match res {
+            Food => 500.0,
+            _ => 0.0,
+        },
+    },
+    CapSource {
+        count: |game| game.city.oil_well,
+        amount: |_, res| match res {
+            Oil => 500.0,
+            _ => 0.0,
+        },
+    },
+    CapSource {
+        count: |game| game.city.warehouse,
+        amount: |_, res| match res {
+            Lumber | Stone => 500.0,
+            Iron | Coal => 250.0,
+            Containers => 10.0,
+            _ => 0.0,
+        },
+    },
+];
+
+/// The cap a resource starts with, `-1.0` means it can't be capped at all
+fn base(res: ResourceType) -> f32 {
+    match res {
+        RNA | DNA | Knowledge | Iron | Mana => 100.0,
+        Food => 250.0,
+        Lumber | Stone => 200.0,
+        Money => 1000.0,
+        Coal => 50.0,
+        Oil => 0.0,
+        Uranium => 10.0,
+        Crates | Containers => 0.0,
+        Plywood | Brick | WroughtIron | SheetMetal => -1.0,
+    }
+}
+
+/// Whether crates and containers can be assigned to the resource
+pub fn crateable(res: ResourceType) -> bool {
+    matches!(res, Food | Lumber | Stone | Iron | Coal)
+}
+
+/// Runs a resource through the cap pipeline: its base cap, then every storage source, then its crates and containers
+pub fn cap(game: &Game, res: ResourceType) -> f32 {
+    let mut cap = base(res);
+    if cap == -1.0 {
+        return cap;
+    }
+
+    for source in SOURCES {
+        if let Some(count) = (source.count)(game) {
+            cap += count as f32 * (source.amount)(game, res);
+        }
+    }
+
+    cap += game.storage.assigned(Crates, res) as f32 * CRATE_SIZE;
+    cap += game.storage.assigned(Containers, res) as f32 * CONTAINER_SIZE;
+
+    // Assigned crates and containers still take up room in the yard
+    if matches!(res, Crates | Containers) {
+        cap -= game.storage.assignments(res).values().sum::<u32>() as f32;
+    }
+
+    cap.max(0.0)
+}
+
+/// Moves a crate or container out of the stockpile and onto `res`
+pub fn assign(game: &mut Game, kind: ResourceType, res: ResourceType) {
+    if game.resources[kind].amount < 1.0 {
+        return;
+    }
+
+    game.mod_res(kind, -1.0, true, false);
+    *game.storage.assignments_mut(kind).entry(res).or_default() += 1;
+    game.resources[kind].max = cap(game, kind);
+    game.resources[res].max = cap(game, res);
+}
+
+/// Takes a crate or container off `res` and puts it back into the stockpile
+pub fn unassign(game: &mut Game, kind: ResourceType, res: ResourceType) {
+    match game.storage.assignments_mut(kind).get_mut(&res) {
+        Some(count) if *count > 0 => *count -= 1,
+        _ => return,
+    }
+
+    game.resources[kind].max = cap(game, kind);
+    game.resources[res].max = cap(game, res);
+    game.mod_res(kind, 1.0, true, false);
+}
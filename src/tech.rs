@@ -0,0 +1,446 @@
+use crate::action::{
+    Action, ActionHolder, Category, ACTION_BASIC_HOUSING, ACTION_COAL_MINE, ACTION_COAL_POWER, ACTION_FARM,
+    ACTION_FISSION_POWER, ACTION_FOUNDRY, ACTION_MINE, ACTION_OIL_POWER, ACTION_OIL_WELL, ACTION_SHED, ACTION_SILO,
+    ACTION_TRADE_POST, ACTION_UNIVERSITY, ACTION_WAREHOUSE,
+};
+use crate::market::Market;
+use crate::message::{Arg, MessageKind, MessageLog};
+use crate::prestige::ACTION_MAD;
+use crate::resource::{Cost, ResourceType::*};
+use crate::{cost, loc, msg, Game, ACTIONS};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+#[derive(Serialize, Deserialize)]
+pub struct Tech {
+    researched: BTreeSet<String>,
+}
+
+impl Tech {
+    pub fn new() -> Self {
+        Self {
+            researched: BTreeSet::new(),
+        }
+    }
+
+    pub fn researched(&self, id: &str) -> bool {
+        self.researched.contains(id)
+    }
+
+    pub fn completed(&self) -> impl Iterator<Item = &'static Research> + '_ {
+        TECHS.iter().filter(|research| self.researched(research.action.id))
+    }
+}
+
+/// A tech along with the techs that must be researched before it is offered
+pub struct Research {
+    pub action: Action,
+    pub reqs: &'static [&'static str],
+    /// Added to the city once the tech is researched
+    pub buildings: &'static [Action],
+}
+
+/// Offers every tech whose requirements have all been researched
+pub fn unlock(tech: &Tech, holder: &mut ActionHolder, log: &mut MessageLog) {
+    for Research { action, reqs, .. } in TECHS {
+        if !holder.unlocked(action.clone()) && reqs.iter().all(|id| tech.researched(id)) {
+            holder.add(Category::Research, action.clone());
+            let title = Arg::Key(action.title.to_string());
+            log.push(MessageKind::Info, msg!("log_research_unlocked", title));
+        }
+    }
+}
+
+/// Puts the techs on offer and the buildings of the researched ones back in place, for a game that was loaded
+pub fn restore(tech: &Tech, holder: &mut ActionHolder) {
+    for Research { action, reqs, .. } in TECHS {
+        if tech.researched(action.id) {
+            // Added and taken out again so it counts as unlocked without being offered
+            holder.add(Category::Research, action.clone());
+            holder.remove(Category::Research, action.id);
+        } else if reqs.iter().all(|id| tech.researched(id)) {
+            holder.add(Category::Research, action.clone());
+        }
+    }
+
+    for research in tech.completed() {
+        for building in research.buildings {
+            holder.add(Category::City, building.clone());
+        }
+    }
+}
+
+/// Pays for a tech and marks it as researched, returns `false` if it couldn't be afforded
+fn research(action: &Action, game: &mut Game) -> bool {
+    if action.pay(game) {
+        game.tech.researched.insert(action.id.to_string());
+
+        let mut holder = ACTIONS.lock().unwrap();
+        holder.remove(Category::Research, action.id);
+        if let Some(research) = TECHS.iter().find(|research| research.action.id == action.id) {
+            for building in research.buildings {
+                holder.add(Category::City, building.clone());
+            }
+        }
+        return true;
+    }
+
+    false
+}
+
+pub const TECHS: &[Research] = &[
+    Research {
+        action: TECH_HOUSING,
+        reqs: &[],
+        buildings: &[ACTION_BASIC_HOUSING],
+    },
+    Research {
+        action: TECH_AGRICULTURE,
+        reqs: &[],
+        buildings: &[ACTION_FARM],
+    },
+    Research {
+        action: TECH_STONE_AXE,
+        reqs: &[],
+        buildings: &[],
+    },
+    Research {
+        action: TECH_MINING,
+        reqs: &[],
+        buildings: &[],
+    },
+    Research {
+        action: TECH_IRRIGATION,
+        reqs: &[TECH_AGRICULTURE.id],
+        buildings: &[],
+    },
+    Research {
+        action: TECH_SCIENCE,
+        reqs: &[TECH_HOUSING.id],
+        buildings: &[ACTION_UNIVERSITY],
+    },
+    Research {
+        action: TECH_CURRENCY,
+        reqs: &[TECH_HOUSING.id],
+        buildings: &[],
+    },
+    Research {
+        action: TECH_GOVERNMENT,
+        reqs: &[TECH_CURRENCY.id],
+        buildings: &[],
+    },
+    Research {
+        action: TECH_FOUNDRY,
+        reqs: &[TECH_HOUSING.id, TECH_STONE_AXE.id],
+        buildings: &[ACTION_FOUNDRY],
+    },
+    Research {
+        action: TECH_METAL_WORKING,
+        reqs: &[TECH_MINING.id],
+        buildings: &[ACTION_MINE],
+    },
+    Research {
+        action: TECH_STORAGE,
+        reqs: &[TECH_FOUNDRY.id],
+        buildings: &[ACTION_SHED, ACTION_SILO],
+    },
+    Research {
+        action: TECH_TRADE,
+        reqs: &[TECH_CURRENCY.id],
+        buildings: &[ACTION_TRADE_POST],
+    },
+    Research {
+        action: TECH_COAL_MINING,
+        reqs: &[TECH_METAL_WORKING.id],
+        buildings: &[ACTION_COAL_MINE],
+    },
+    Research {
+        action: TECH_ELECTRICITY,
+        reqs: &[TECH_COAL_MINING.id, TECH_FOUNDRY.id],
+        buildings: &[ACTION_COAL_POWER],
+    },
+    Research {
+        action: TECH_OIL,
+        reqs: &[TECH_ELECTRICITY.id],
+        buildings: &[ACTION_OIL_WELL, ACTION_OIL_POWER],
+    },
+    Research {
+        action: TECH_FISSION,
+        reqs: &[TECH_OIL.id, TECH_SCIENCE.id],
+        buildings: &[ACTION_FISSION_POWER],
+    },
+    Research {
+        action: TECH_MAD,
+        reqs: &[TECH_FISSION.id],
+        buildings: &[ACTION_MAD],
+    },
+    Research {
+        action: TECH_WAREHOUSE,
+        reqs: &[TECH_STORAGE.id, TECH_METAL_WORKING.id],
+        buildings: &[ACTION_WAREHOUSE],
+    },
+];
+
+pub const TECH_HOUSING: Action = Action {
+    id: "tech-housing",
+    title: "tech_housing_title",
+    desc: "tech_housing_desc",
+    effect: Some(|_| loc!("tech_housing_effect")),
+    cost: Some(|_| cost!(Knowledge => 10)),
+    action: |s, game| {
+        research(s, game);
+    },
+    count: None,
+};
+
+pub const TECH_AGRICULTURE: Action = Action {
+    id: "tech-agriculture",
+    title: "tech_agriculture_title",
+    desc: "tech_agriculture_desc",
+    effect: Some(|_| loc!("tech_agriculture_effect")),
+    cost: Some(|_| cost!(Knowledge => 10)),
+    action: |s, game| {
+        if research(s, game) {
+            game.city.farm = Some(0);
+            game.civic.jobs.farmer = Some(0);
+        }
+    },
+    count: None,
+};
+
+pub const TECH_STONE_AXE: Action = Action {
+    id: "tech-stone_axe",
+    title: "tech_stone_axe_title",
+    desc: "tech_stone_axe_desc",
+    effect: Some(|_| loc!("tech_stone_axe_effect")),
+    cost: Some(|_| cost!(Knowledge => 45)),
+    action: |s, game| {
+        if research(s, game) {
+            game.civic.jobs.lumberjack = Some(0);
+        }
+    },
+    count: None,
+};
+
+pub const TECH_MINING: Action = Action {
+    id: "tech-mining",
+    title: "tech_mining_title",
+    desc: "tech_mining_desc",
+    effect: Some(|_| loc!("tech_mining_effect")),
+    cost: Some(|_| cost!(Knowledge => 45)),
+    action: |s, game| {
+        if research(s, game) {
+            game.civic.jobs.quarry_worker = Some(0);
+        }
+    },
+    count: None,
+};
+
+pub const TECH_IRRIGATION: Action = Action {
+    id: "tech-irrigation",
+    title: "tech_irrigation_title",
+    desc: "tech_irrigation_desc",
+    effect: Some(|_| loc!("tech_irrigation_effect")),
+    cost: Some(|_| cost!(Knowledge => 55)),
+    action: |s, game| {
+        research(s, game);
+    },
+    count: None,
+};
+
+pub const TECH_SCIENCE: Action = Action {
+    id: "tech-science",
+    title: "tech_science_title",
+    desc: "tech_science_desc",
+    effect: Some(|_| loc!("tech_science_effect")),
+    cost: Some(|_| cost!(Knowledge => 65)),
+    action: |s, game| {
+        if research(s, game) {
+            game.city.university = Some(0);
+            game.civic.jobs.professor = Some(0);
+        }
+    },
+    count: None,
+};
+
+pub const TECH_CURRENCY: Action = Action {
+    id: "tech-currency",
+    title: "tech_currency_title",
+    desc: "tech_currency_desc",
+    effect: Some(|_| loc!("tech_currency_effect")),
+    cost: Some(|_| cost!(Knowledge => 22)),
+    action: |s, game| {
+        if research(s, game) {
+            game.resources.money.display = true;
+            game.civic.tax_rate = Some(20);
+        }
+    },
+    count: None,
+};
+
+pub const TECH_GOVERNMENT: Action = Action {
+    id: "tech-government",
+    title: "tech_government_title",
+    desc: "tech_government_desc",
+    effect: Some(|_| loc!("tech_government_effect")),
+    cost: Some(|_| cost!(Knowledge => 80)),
+    action: |s, game| {
+        if research(s, game) {
+            game.civic.government_cooldown = Some(0);
+        }
+    },
+    count: None,
+};
+
+pub const TECH_FOUNDRY: Action = Action {
+    id: "tech-foundry",
+    title: "tech_foundry_title",
+    desc: "tech_foundry_desc",
+    effect: Some(|_| loc!("tech_foundry_effect")),
+    cost: Some(|_| cost!(Knowledge => 65)),
+    action: |s, game| {
+        if research(s, game) {
+            game.city.foundry = Some(0);
+            game.resources.plywood.display = true;
+            game.resources.brick.display = true;
+        }
+    },
+    count: None,
+};
+
+pub const TECH_METAL_WORKING: Action = Action {
+    id: "tech-metal_working",
+    title: "tech_metal_working_title",
+    desc: "tech_metal_working_desc",
+    effect: Some(|_| loc!("tech_metal_working_effect")),
+    cost: Some(|_| cost!(Knowledge => 90)),
+    action: |s, game| {
+        if research(s, game) {
+            game.city.mine = Some(0);
+            game.civic.jobs.miner = Some(0);
+            game.resources.iron.display = true;
+            game.resources.wrought_iron.display = true;
+            game.resources.sheet_metal.display = true;
+        }
+    },
+    count: None,
+};
+
+pub const TECH_STORAGE: Action = Action {
+    id: "tech-storage",
+    title: "tech_storage_title",
+    desc: "tech_storage_desc",
+    effect: Some(|_| loc!("tech_storage_effect")),
+    cost: Some(|_| cost!(Knowledge => 100)),
+    action: |s, game| {
+        if research(s, game) {
+            game.city.shed = Some(0);
+            game.city.silo = Some(0);
+            game.resources.crates.display = true;
+        }
+    },
+    count: None,
+};
+
+pub const TECH_WAREHOUSE: Action = Action {
+    id: "tech-warehouse",
+    title: "tech_warehouse_title",
+    desc: "tech_warehouse_desc",
+    effect: Some(|_| loc!("tech_warehouse_effect")),
+    cost: Some(|_| cost!(Knowledge => 180)),
+    action: |s, game| {
+        if research(s, game) {
+            game.city.warehouse = Some(0);
+            game.resources.containers.display = true;
+        }
+    },
+    count: None,
+};
+
+pub const TECH_TRADE: Action = Action {
+    id: "tech-trade",
+    title: "tech_trade_title",
+    desc: "tech_trade_desc",
+    effect: Some(|_| loc!("tech_trade_effect")),
+    cost: Some(|_| cost!(Knowledge => 60)),
+    action: |s, game| {
+        if research(s, game) {
+            game.city.trade_post = Some(0);
+            game.market = Market::generate(&game.rng);
+        }
+    },
+    count: None,
+};
+
+pub const TECH_COAL_MINING: Action = Action {
+    id: "tech-coal_mining",
+    title: "tech_coal_mining_title",
+    desc: "tech_coal_mining_desc",
+    effect: Some(|_| loc!("tech_coal_mining_effect")),
+    cost: Some(|_| cost!(Knowledge => 120)),
+    action: |s, game| {
+        if research(s, game) {
+            game.city.coal_mine = Some(0);
+            game.civic.jobs.coal_miner = Some(0);
+            game.resources.coal.display = true;
+        }
+    },
+    count: None,
+};
+
+pub const TECH_ELECTRICITY: Action = Action {
+    id: "tech-electricity",
+    title: "tech_electricity_title",
+    desc: "tech_electricity_desc",
+    effect: Some(|_| loc!("tech_electricity_effect")),
+    cost: Some(|_| cost!(Knowledge => 200)),
+    action: |s, game| {
+        if research(s, game) {
+            game.city.coal_power = Some(0);
+        }
+    },
+    count: None,
+};
+
+pub const TECH_OIL: Action = Action {
+    id: "tech-oil",
+    title: "tech_oil_title",
+    desc: "tech_oil_desc",
+    effect: Some(|_| loc!("tech_oil_effect")),
+    cost: Some(|_| cost!(Knowledge => 300)),
+    action: |s, game| {
+        if research(s, game) {
+            game.city.oil_well = Some(0);
+            game.city.oil_power = Some(0);
+            game.resources.oil.display = true;
+        }
+    },
+    count: None,
+};
+
+pub const TECH_FISSION: Action = Action {
+    id: "tech-fission",
+    title: "tech_fission_title",
+    desc: "tech_fission_desc",
+    effect: Some(|_| loc!("tech_fission_effect")),
+    cost: Some(|_| cost!(Knowledge => 600)),
+    action: |s, game| {
+        if research(s, game) {
+            game.city.fission_power = Some(0);
+            game.resources.uranium.display = true;
+        }
+    },
+    count: None,
+};
+
+pub const TECH_MAD: Action = Action {
+    id: "tech-mad",
+    title: "tech_mad_title",
+    desc: "tech_mad_desc",
+    effect: Some(|_| loc!("tech_mad_effect")),
+    cost: Some(|_| cost!(Knowledge => 1000, Uranium => 50)),
+    action: |s, game| {
+        research(s, game);
+    },
+    count: None,
+};
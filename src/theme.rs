@@ -0,0 +1,191 @@
+use crate::loc;
+use crate::resource::ResourceType;
+use imgui::{Style, StyleColor};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, collections::HashMap, path::Path};
+
+/// Where user themes are looked for, one json file per theme named after the file
+pub const THEMES_DIR: &str = "themes";
+/// Theme used when none is configured or the configured one is gone
+pub const DEFAULT_THEME: &str = "Dark";
+
+pub type Color = [f32; 4];
+
+/// The imgui palette a theme starts from before its own colors are laid over it
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Base {
+    Dark,
+    Light,
+    Classic,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    /// Taken from the file name for user themes
+    #[serde(skip)]
+    pub name: String,
+    pub base: Base,
+    /// Style colors by their imgui name, like `WindowBg` or `Text`
+    pub colors: HashMap<String, Color>,
+    /// Colors resource names are drawn in, the ones missing are drawn in the text color
+    pub resources: HashMap<ResourceType, Color>,
+    /// Background of the count drawn on action buttons
+    pub badge: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    pub fn dark() -> Self {
+        Self {
+            name: DEFAULT_THEME.to_string(),
+            base: Base::Dark,
+            colors: HashMap::new(),
+            resources: HashMap::new(),
+            badge: [0.16, 0.16, 0.16, 1.0],
+        }
+    }
+
+    pub fn light() -> Self {
+        Self {
+            name: "Light".to_string(),
+            base: Base::Light,
+            colors: HashMap::new(),
+            resources: HashMap::new(),
+            badge: [0.8, 0.8, 0.8, 1.0],
+        }
+    }
+
+    pub fn classic() -> Self {
+        Self {
+            name: "Classic".to_string(),
+            base: Base::Classic,
+            colors: HashMap::new(),
+            resources: HashMap::new(),
+            badge: [0.27, 0.27, 0.54, 1.0],
+        }
+    }
+
+    pub fn high_contrast() -> Self {
+        const WHITE: Color = [1.0, 1.0, 1.0, 1.0];
+        const BLACK: Color = [0.0, 0.0, 0.0, 1.0];
+        const YELLOW: Color = [1.0, 1.0, 0.0, 1.0];
+        const BLUE: Color = [0.0, 0.2, 0.8, 1.0];
+
+        let colors = [
+            ("Text", WHITE),
+            ("TextDisabled", [0.75, 0.75, 0.75, 1.0]),
+            ("WindowBg", BLACK),
+            ("ChildBg", BLACK),
+            ("PopupBg", BLACK),
+            ("Border", WHITE),
+            ("FrameBg", BLACK),
+            ("FrameBgHovered", BLUE),
+            ("FrameBgActive", BLUE),
+            ("Button", BLACK),
+            ("ButtonHovered", BLUE),
+            ("ButtonActive", YELLOW),
+            ("Header", BLUE),
+            ("HeaderHovered", BLUE),
+            ("Tab", BLACK),
+            ("TabHovered", BLUE),
+            ("TabActive", BLUE),
+            ("CheckMark", YELLOW),
+            ("SliderGrab", YELLOW),
+            ("PlotHistogram", YELLOW),
+            ("TableRowBg", BLACK),
+            ("TableRowBgAlt", [0.12, 0.12, 0.12, 1.0]),
+        ];
+
+        Self {
+            name: "High contrast".to_string(),
+            base: Base::Dark,
+            colors: colors.iter().map(|(name, color)| (name.to_string(), *color)).collect(),
+            resources: HashMap::new(),
+            badge: BLUE,
+        }
+    }
+
+    pub fn builtin() -> [Theme; 4] {
+        [Self::dark(), Self::light(), Self::classic(), Self::high_contrast()]
+    }
+
+    /// Translation key of the title, only the built-in themes have one
+    pub fn key(&self) -> Option<&'static str> {
+        match self.name.as_str() {
+            "Dark" => Some("theme_dark"),
+            "Light" => Some("theme_light"),
+            "Classic" => Some("theme_classic"),
+            "High contrast" => Some("theme_high_contrast"),
+            _ => None,
+        }
+    }
+
+    pub fn title(&self) -> Cow<'static, str> {
+        match self.key() {
+            Some(key) => loc!(key),
+            None => Cow::Owned(self.name.clone()),
+        }
+    }
+
+    pub fn apply(&self, style: &mut Style) {
+        match self.base {
+            Base::Dark => style.use_dark_colors(),
+            Base::Light => style.use_light_colors(),
+            Base::Classic => style.use_classic_colors(),
+        };
+
+        for color in StyleColor::VARIANTS {
+            if let Some(value) = self.colors.get(&format!("{color:?}")) {
+                style[color] = *value;
+            }
+        }
+    }
+
+    pub fn resource(&self, res: ResourceType) -> Option<Color> {
+        self.resources.get(&res).copied()
+    }
+}
+
+/// The built in themes followed by every theme in [`THEMES_DIR`], files that don't parse are left out and
+/// reported in `errors`
+pub fn themes(errors: &mut Vec<String>) -> Vec<Theme> {
+    let mut custom: Vec<Theme> = std::fs::read_dir(THEMES_DIR)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "json" {
+                return None;
+            }
+            read(&path).map_err(|error| errors.push(error)).ok()
+        })
+        .collect();
+    custom.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut themes = Theme::builtin().to_vec();
+    themes.extend(custom);
+    themes
+}
+
+/// Reads a user theme, naming it after its file
+pub fn read(path: &Path) -> Result<Theme, String> {
+    let content = std::fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))?;
+    let mut theme: Theme = serde_json::from_str(&content).map_err(|err| format!("{}: {err}", path.display()))?;
+    theme.name = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+    Ok(theme)
+}
+
+/// Looks a theme up by name, falling back to the default one
+pub fn find(themes: &[Theme], name: &str) -> Theme {
+    themes
+        .iter()
+        .find(|theme| theme.name == name)
+        .cloned()
+        .unwrap_or_default()
+}
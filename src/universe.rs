@@ -0,0 +1,109 @@
+use crate::loc;
+use crate::resource::ResourceType::Mana;
+use crate::Game;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use strum::{EnumIter, IntoEnumIterator};
+
+/// Mana gained every second in the magic universe, before citizens join in
+const MANA_BASE: f32 = 0.1;
+/// Mana every citizen channels per second
+const MANA_CITIZEN: f32 = 0.01;
+
+#[derive(Debug, Clone, Copy, PartialEq, EnumIter, Serialize, Deserialize)]
+pub enum Universe {
+    Standard,
+    Heavy,
+    Antimatter,
+    Evil,
+    Micro,
+    Magic,
+}
+
+impl Universe {
+    pub fn iter() -> impl Iterator<Item = Universe> {
+        <Self as IntoEnumIterator>::iter()
+    }
+
+    /// Translation key of the title
+    pub fn title_key(&self) -> &'static str {
+        match self {
+            Universe::Standard => "universe_standard_title",
+            Universe::Heavy => "universe_heavy_title",
+            Universe::Antimatter => "universe_antimatter_title",
+            Universe::Evil => "universe_evil_title",
+            Universe::Micro => "universe_micro_title",
+            Universe::Magic => "universe_magic_title",
+        }
+    }
+
+    pub fn title(&self) -> Cow<'static, str> {
+        loc!(self.title_key())
+    }
+
+    /// Translation key of the description
+    pub fn desc_key(&self) -> &'static str {
+        match self {
+            Universe::Standard => "universe_standard_desc",
+            Universe::Heavy => "universe_heavy_desc",
+            Universe::Antimatter => "universe_antimatter_desc",
+            Universe::Evil => "universe_evil_desc",
+            Universe::Micro => "universe_micro_desc",
+            Universe::Magic => "universe_magic_desc",
+        }
+    }
+
+    pub fn description(&self) -> Cow<'static, str> {
+        loc!(self.desc_key())
+    }
+
+    /// Multiplier applied to the cost of every action
+    pub fn costs(&self) -> f32 {
+        match self {
+            Universe::Heavy => 1.25,
+            Universe::Micro => 0.75,
+            _ => 1.0,
+        }
+    }
+
+    /// Multiplier applied to everything that is produced
+    pub fn production(&self) -> f32 {
+        match self {
+            Universe::Heavy => 0.9,
+            Universe::Antimatter => 1.1,
+            Universe::Evil => 0.9,
+            _ => 1.0,
+        }
+    }
+
+    /// Multiplier applied to the plasmids a reset awards
+    pub fn plasmids(&self) -> f32 {
+        match self {
+            Universe::Heavy => 1.25,
+            Universe::Antimatter => 0.5,
+            Universe::Evil => 1.2,
+            Universe::Micro => 0.5,
+            _ => 1.0,
+        }
+    }
+}
+
+/// Channels mana in the magic universe, every citizen adds to the flow
+pub fn channel(game: &mut Game, time_mult: f32) {
+    if game.universe() != Universe::Magic {
+        return;
+    }
+
+    let mana = MANA_BASE + game.civic.citizens as f32 * MANA_CITIZEN;
+    game.mod_res(Mana, mana * time_mult, false, false);
+}
+
+/// Production bonus from stored mana, up to 10% with full mana
+pub fn mana_mult(game: &Game) -> f32 {
+    let mana = &game.resources.mana;
+    if mana.max <= 0.0 {
+        return 1.0;
+    }
+
+    1.0 + 0.1 * mana.amount / mana.max
+}
@@ -1,5 +1,8 @@
 use imgui::{sys::*, Ui};
-use std::ptr;
+use std::{
+    ptr,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 // Code adapted from https://github.com/ocornut/imgui/issues/3518
 struct StatusBar;
@@ -92,8 +95,47 @@ impl Drop for StatusBarToken {
     }
 }
 
+/// Lets windows be docked anywhere over the main viewport while keeping its center see-through
+pub fn dockspace() {
+    unsafe {
+        igDockSpaceOverViewport(
+            igGetMainViewport(),
+            ImGuiDockNodeFlags_PassthruCentralNode as i32,
+            ptr::null(),
+        );
+    }
+}
+
+/// Pulls the next window out of whatever dock node it sits in
+pub fn undock() {
+    unsafe { igSetNextWindowDockID(0, ImGuiCond_Always as i32) }
+}
+
 pub fn right_align<T: AsRef<str>>(ui: &Ui, text: T) {
     let y = ui.cursor_pos()[1];
     ui.set_cursor_pos([ui.content_region_max()[0] - ui.calc_text_size(&text)[0], y]);
     ui.text(text);
 }
+
+/// Seconds since the unix epoch
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+/// Formats a unix timestamp as a `yyyy-mm-dd` date in UTC
+pub fn date(time: u64) -> String {
+    // Civil from days, see http://howardhinnant.github.io/date_algorithms.html
+    let days = (time / 86400) as i64 + 719468;
+    let era = days.div_euclid(146097);
+    let doe = days - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + (month <= 2) as i64;
+
+    format!("{year:04}-{month:02}-{day:02}")
+}
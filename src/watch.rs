@@ -0,0 +1,44 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+/// Polls directories for files that were edited, added or removed, only used by debug builds to hot reload
+pub struct Watcher {
+    dirs: Vec<&'static str>,
+    stamps: HashMap<PathBuf, SystemTime>,
+}
+
+impl Watcher {
+    /// Files already there when the watcher is created don't count as changed
+    pub fn new(dirs: &[&'static str]) -> Self {
+        let dirs = dirs.to_vec();
+        let stamps = scan(&dirs);
+        Self { dirs, stamps }
+    }
+
+    /// Every file that changed since the last poll
+    pub fn poll(&mut self) -> Vec<PathBuf> {
+        let stamps = scan(&self.dirs);
+        let mut changed: Vec<PathBuf> = stamps
+            .iter()
+            .filter(|(path, stamp)| self.stamps.get(*path) != Some(stamp))
+            .map(|(path, _)| path.clone())
+            .collect();
+        changed.extend(self.stamps.keys().filter(|path| !stamps.contains_key(*path)).cloned());
+
+        self.stamps = stamps;
+        changed
+    }
+}
+
+fn scan(dirs: &[&str]) -> HashMap<PathBuf, SystemTime> {
+    dirs.iter()
+        .flat_map(|dir| std::fs::read_dir(Path::new(dir)).into_iter().flatten())
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            Some((entry.path(), entry.metadata().ok()?.modified().ok()?))
+        })
+        .collect()
+}